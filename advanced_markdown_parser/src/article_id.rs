@@ -0,0 +1,174 @@
+use std::path::{Path, PathBuf};
+
+/// Why a requested article path was refused
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArticlePathError {
+    /// A well-formed path with no article behind it
+    NotFound(String),
+    /// A path that leaves the content root or names something other than an
+    /// article: absolute, `..`, encoded separators, hidden or non-markdown files
+    Forbidden(String),
+}
+
+impl ArticlePathError {
+    /// HTTP status to answer with
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ArticlePathError::NotFound(_) => 404,
+            ArticlePathError::Forbidden(_) => 403,
+        }
+    }
+}
+
+impl std::fmt::Display for ArticlePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArticlePathError::NotFound(path) => write!(f, "Article not found: {}", path),
+            ArticlePathError::Forbidden(path) => write!(f, "Forbidden article path: {}", path),
+        }
+    }
+}
+
+impl std::error::Error for ArticlePathError {}
+
+/// Percent-encoded `/`, `\` and `.`, which must not survive into a path
+const ENCODED_SEPARATORS: [&str; 3] = ["%2f", "%5c", "%2e"];
+
+/// An article's path relative to the content root (`Series/02-people.md`)
+///
+/// Parsing only accepts plain relative paths to markdown files; [`resolve`]
+/// then checks the file on disk is really inside the root.
+///
+/// [`resolve`]: ArticleId::resolve
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArticleId(String);
+
+impl ArticleId {
+    pub fn parse(path: &str) -> Result<Self, ArticlePathError> {
+        let forbidden = || Err(ArticlePathError::Forbidden(path.to_string()));
+
+        let lower = path.to_ascii_lowercase();
+        if path.contains(['\\', '\0']) || ENCODED_SEPARATORS.iter().any(|e| lower.contains(e)) {
+            return forbidden();
+        }
+        // Absolute, including Windows drive prefixes such as `C:`
+        if path.starts_with('/') || path.contains(':') {
+            return forbidden();
+        }
+        // Empty segments, `.`, `..` and hidden files or folders
+        if path.split('/').any(|segment| segment.is_empty() || segment.starts_with('.')) {
+            return forbidden();
+        }
+        if Path::new(path).extension().and_then(|e| e.to_str()) != Some("md") {
+            return forbidden();
+        }
+
+        Ok(ArticleId(path.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The article's file, canonicalised, as long as it is a markdown file
+    /// inside `root` once symlinks are followed
+    pub fn resolve(&self, root: &Path) -> Result<PathBuf, ArticlePathError> {
+        let not_found = || ArticlePathError::NotFound(self.0.clone());
+
+        let root = root.canonicalize().map_err(|_| not_found())?;
+        let file = root.join(&self.0).canonicalize().map_err(|_| not_found())?;
+
+        if !file.starts_with(&root) || file.extension().and_then(|e| e.to_str()) != Some("md") {
+            return Err(ArticlePathError::Forbidden(self.0.clone()));
+        }
+        if !file.is_file() {
+            return Err(not_found());
+        }
+
+        Ok(file)
+    }
+}
+
+impl std::fmt::Display for ArticleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A content root with `Series/01-intro.md`, a secret file beside the
+    /// root and symlinks pointing out of it
+    fn content_root(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("article-id-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let root = dir.join("articles");
+        std::fs::create_dir_all(root.join("Series")).unwrap();
+        std::fs::write(root.join("Series/01-intro.md"), "# Intro").unwrap();
+        std::fs::write(root.join("notes.txt"), "not an article").unwrap();
+        std::fs::write(dir.join("secret.md"), "# Secret").unwrap();
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(dir.join("secret.md"), root.join("escape.md")).unwrap();
+            std::os::unix::fs::symlink(&dir, root.join("outside")).unwrap();
+            std::os::unix::fs::symlink(root.join("notes.txt"), root.join("notes.md")).unwrap();
+        }
+
+        root
+    }
+
+    fn forbidden(path: &str) -> bool {
+        matches!(ArticleId::parse(path), Err(ArticlePathError::Forbidden(_)))
+    }
+
+    #[test]
+    fn test_parse_rejects_traversal() {
+        for path in [
+            "../secret.md",
+            "Series/../../secret.md",
+            "....//secret.md",
+            "./Series/01-intro.md",
+            "/etc/passwd.md",
+            "C:/secret.md",
+            "Series\\..\\..\\secret.md",
+            "..%2fsecret.md",
+            "Series%2F..%2F..%2Fsecret.md",
+            "%2e%2e/secret.md",
+            ".obsidian/app.md",
+            "Series//01-intro.md",
+            "Series/01-intro.md\0.md",
+            "Cargo.toml",
+            "Series/",
+            "",
+        ] {
+            assert!(forbidden(path), "{:?} should be forbidden", path);
+        }
+
+        let id = ArticleId::parse("Series/01-intro.md").unwrap();
+        assert_eq!(id.as_str(), "Series/01-intro.md");
+    }
+
+    #[test]
+    fn test_resolve_stays_inside_root() {
+        let root = content_root("resolve");
+
+        let file = ArticleId::parse("Series/01-intro.md").unwrap().resolve(&root).unwrap();
+        assert_eq!(file, root.canonicalize().unwrap().join("Series/01-intro.md"));
+
+        let missing = ArticleId::parse("Series/02-missing.md").unwrap().resolve(&root);
+        assert_eq!(missing, Err(ArticlePathError::NotFound("Series/02-missing.md".to_string())));
+        assert_eq!(missing.unwrap_err().status_code(), 404);
+
+        #[cfg(unix)]
+        for path in ["escape.md", "outside/secret.md", "notes.md"] {
+            let resolved = ArticleId::parse(path).unwrap().resolve(&root);
+            assert_eq!(resolved, Err(ArticlePathError::Forbidden(path.to_string())), "{}", path);
+        }
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A date from article front matter (`date`, `updated`)
+///
+/// Accepts either a plain calendar date (`"2025-11-20"`, `"2025-9-1"`) or an
+/// RFC 3339 date-time (`"2025-11-20T09:30:00+02:00"`). Ordering compares the
+/// instant, with plain dates treated as midnight UTC.
+#[derive(Clone, Copy, Debug)]
+pub enum ArticleDate {
+    /// A calendar date without a time of day
+    Date(NaiveDate),
+    /// A full date-time with a UTC offset
+    DateTime(DateTime<FixedOffset>),
+}
+
+/// Error returned when a front matter date cannot be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateParseError {
+    /// The rejected input
+    pub input: String,
+}
+
+impl fmt::Display for DateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid date \"{}\": expected YYYY-MM-DD or an RFC 3339 date-time",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for DateParseError {}
+
+impl ArticleDate {
+    /// Parse a plain date or an RFC 3339 date-time
+    pub fn parse(input: &str) -> Result<Self, DateParseError> {
+        let trimmed = input.trim();
+        let error = || DateParseError {
+            input: input.to_string(),
+        };
+
+        if trimmed.contains(['T', 't', ' ']) {
+            return DateTime::parse_from_rfc3339(trimmed)
+                .map(ArticleDate::DateTime)
+                .map_err(|_| error());
+        }
+
+        // Split manually so unpadded months and days ("2025-9-1") are accepted
+        let mut parts = trimmed.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error());
+        };
+
+        let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if year.len() != 4 || !all_digits(year) || month.len() > 2 || day.len() > 2 {
+            return Err(error());
+        }
+        if !all_digits(month) || !all_digits(day) {
+            return Err(error());
+        }
+
+        let year = year.parse().map_err(|_| error())?;
+        let month = month.parse().map_err(|_| error())?;
+        let day = day.parse().map_err(|_| error())?;
+
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(ArticleDate::Date)
+            .ok_or_else(error)
+    }
+
+    /// The calendar date, ignoring any time of day
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            ArticleDate::Date(date) => *date,
+            ArticleDate::DateTime(date_time) => date_time.date_naive(),
+        }
+    }
+
+    /// Seconds since the Unix epoch (plain dates count as midnight UTC)
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            ArticleDate::Date(date) => date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp(),
+            ArticleDate::DateTime(date_time) => date_time.timestamp(),
+        }
+    }
+
+    /// Human readable form for display, e.g. "November 20, 2025"
+    pub fn to_long_string(&self) -> String {
+        self.date().format("%B %-d, %Y").to_string()
+    }
+
+    /// The instant as a date-time, with plain dates at midnight UTC
+    pub fn to_date_time(&self) -> DateTime<FixedOffset> {
+        match self {
+            ArticleDate::Date(date) => date.and_hms_opt(0, 0, 0).unwrap().and_utc().fixed_offset(),
+            ArticleDate::DateTime(date_time) => *date_time,
+        }
+    }
+
+    /// RFC 2822 form for RSS, e.g. "Thu, 20 Nov 2025 09:30:00 +0200"
+    pub fn to_rfc2822(&self) -> String {
+        self.to_date_time().to_rfc2822()
+    }
+
+    /// RFC 3339 form for Atom and JSON Feed, e.g. "2025-11-20T00:00:00Z"
+    pub fn to_rfc3339(&self) -> String {
+        self.to_date_time().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    }
+}
+
+impl From<SystemTime> for ArticleDate {
+    fn from(time: SystemTime) -> Self {
+        ArticleDate::DateTime(DateTime::<Utc>::from(time).fixed_offset())
+    }
+}
+
+impl FromStr for ArticleDate {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArticleDate::parse(s)
+    }
+}
+
+impl fmt::Display for ArticleDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArticleDate::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            ArticleDate::DateTime(date_time) => write!(f, "{}", date_time.to_rfc3339()),
+        }
+    }
+}
+
+impl PartialEq for ArticleDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ArticleDate {}
+
+impl PartialOrd for ArticleDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ArticleDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp().cmp(&other.timestamp())
+    }
+}
+
+impl Serialize for ArticleDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ArticleDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        ArticleDate::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_and_unpadded_dates() {
+        let padded = ArticleDate::parse("2025-09-01").unwrap();
+        let unpadded = ArticleDate::parse("2025-9-1").unwrap();
+
+        assert_eq!(padded, unpadded);
+        assert_eq!(unpadded.to_string(), "2025-09-01");
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let date = ArticleDate::parse("2025-11-20T09:30:00+02:00").unwrap();
+
+        assert!(matches!(date, ArticleDate::DateTime(_)));
+        assert_eq!(date.date(), NaiveDate::from_ymd_opt(2025, 11, 20).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_invalid_dates() {
+        for input in ["2025-13-01", "2025-02-30", "20-11-2025", "yesterday", "2025-11-20T25:00:00Z"] {
+            assert!(ArticleDate::parse(input).is_err(), "{input} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_ordering_is_chronological() {
+        let september = ArticleDate::parse("2025-9-1").unwrap();
+        let november = ArticleDate::parse("2025-11-20").unwrap();
+
+        // As strings these compare the other way round
+        assert!("2025-9-1" > "2025-11-20");
+        assert!(september < november);
+    }
+}
@@ -0,0 +1,115 @@
+/// Plain-text opening of an article body, at most `max_chars` characters
+///
+/// Headings, code blocks, images, tables and block quotes are skipped and
+/// inline markdown is stripped, so the result can be shown in cards and
+/// search results. Longer text is cut at a word boundary and ends with `…`.
+pub fn excerpt(content: &str, max_chars: usize) -> String {
+    let mut text = String::new();
+    let mut in_code_block = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        if line.is_empty() {
+            // Stop at the end of the first paragraph once we have enough
+            if text.chars().count() >= max_chars {
+                break;
+            }
+            continue;
+        }
+
+        let skipped = ["#", "!", "|", ">", "<", "---", "***"];
+        if skipped.iter().any(|prefix| line.starts_with(prefix)) {
+            continue;
+        }
+
+        let line = line
+            .trim_start_matches(['-', '*', '+'])
+            .trim_start();
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&strip_inline_markdown(line));
+
+        if text.chars().count() >= max_chars {
+            break;
+        }
+    }
+
+    truncate_at_word(&text, max_chars)
+}
+
+/// Remove emphasis markers and code ticks, and replace `[text](url)` with `text`
+/// Underscores inside words (`snake_case`) are kept
+pub(crate) fn strip_inline_markdown(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    let mut previous = ' ';
+
+    while let Some(c) = chars.next() {
+        let inside_word = previous.is_alphanumeric() && chars.peek().is_some_and(|next| next.is_alphanumeric());
+        previous = c;
+
+        match c {
+            '_' if inside_word => result.push(c),
+            '*' | '_' | '`' => {}
+            // Drop the `(url)` that follows a link's text
+            ']' if chars.peek() == Some(&'(') => {
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            '[' | ']' => {}
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn truncate_at_word(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max_chars).collect();
+    let cut = match cut.rfind(' ') {
+        Some(index) if index > 0 => &cut[..index],
+        _ => cut.as_str(),
+    };
+
+    format!("{}…", cut.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_excerpt_skips_headings_and_code() {
+        let content = "# Title\n\n```rust\nfn main() {}\n```\n\nRust is a *systems* language.\nSee [the book](https://doc.rust-lang.org/book/).\n\nSecond paragraph.";
+
+        assert_eq!(
+            excerpt(content, 200),
+            "Rust is a systems language. See the book. Second paragraph."
+        );
+    }
+
+    #[test]
+    fn test_excerpt_truncates_at_word_boundary() {
+        let content = "The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(excerpt(content, 18), "The quick brown…");
+        assert_eq!(excerpt(content, 100), content);
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::ArticleDate;
+
+/// How much of each article a feed carries
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedContent {
+    /// The whole article rendered to HTML
+    #[default]
+    Full,
+    /// Only the summary, with a link to the article
+    Summary,
+}
+
+impl FeedContent {
+    /// Parse a `content=` query value, `None` when it isn't one of ours
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "full" => Some(FeedContent::Full),
+            "summary" => Some(FeedContent::Summary),
+            _ => None,
+        }
+    }
+}
+
+/// One article in a feed
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FeedItem {
+    /// Stable identifier; must not change when the article is renamed or moved
+    pub id: String,
+    /// Absolute URL of the article
+    pub url: String,
+    pub title: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Rendered article body, only set for full-content feeds
+    #[serde(default)]
+    pub content_html: Option<String>,
+    pub published: ArticleDate,
+    #[serde(default)]
+    pub updated: Option<ArticleDate>,
+    /// Author display names
+    #[serde(default)]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl FeedItem {
+    /// When the item last changed, never earlier than its publication
+    pub fn last_modified(&self) -> ArticleDate {
+        self.updated.filter(|updated| *updated > self.published).unwrap_or(self.published)
+    }
+}
+
+/// A feed of articles, rendered as RSS 2.0, Atom 1.0 or JSON Feed 1.1
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    /// Absolute URL of the site (or of the page the feed mirrors)
+    pub home_page_url: String,
+    /// Absolute URL the feed itself is served from
+    pub feed_url: String,
+    /// BCP 47 language tag
+    pub language: String,
+    /// Used for items that name no author
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Newest first
+    pub items: Vec<FeedItem>,
+}
+
+/// Escape text for XML element content and attribute values
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab and newlines aren't valid XML 1.0
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Feed {
+    /// Most recent change across all items; the Unix epoch for an empty feed
+    pub fn updated(&self) -> ArticleDate {
+        self.items
+            .iter()
+            .map(FeedItem::last_modified)
+            .max()
+            .unwrap_or_else(|| ArticleDate::Date(chrono::NaiveDate::default()))
+    }
+
+    fn authors_of<'a>(&'a self, item: &'a FeedItem) -> Vec<&'a str> {
+        if item.authors.is_empty() {
+            self.author.iter().map(String::as_str).collect()
+        } else {
+            item.authors.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Render as RSS 2.0
+    ///
+    /// GUIDs are the item IDs, marked as not being permalinks. Full content
+    /// goes in `content:encoded` so readers still get the summary in
+    /// `description`.
+    pub fn to_rss(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(concat!(
+            "<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\"",
+            " xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"",
+            " xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n",
+        ));
+        xml.push_str("<channel>\n");
+        let _ = writeln!(xml, "<title>{}</title>", escape_xml(&self.title));
+        let _ = writeln!(xml, "<link>{}</link>", escape_xml(&self.home_page_url));
+        let _ = writeln!(xml, "<description>{}</description>", escape_xml(&self.description));
+        let _ = writeln!(xml, "<language>{}</language>", escape_xml(&self.language));
+        let _ = writeln!(xml, "<lastBuildDate>{}</lastBuildDate>", self.updated().to_rfc2822());
+        let _ = writeln!(
+            xml,
+            "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>",
+            escape_xml(&self.feed_url)
+        );
+
+        for item in &self.items {
+            xml.push_str("<item>\n");
+            let _ = writeln!(xml, "<title>{}</title>", escape_xml(&item.title));
+            let _ = writeln!(xml, "<link>{}</link>", escape_xml(&item.url));
+            let _ = writeln!(xml, "<guid isPermaLink=\"false\">{}</guid>", escape_xml(&item.id));
+            let _ = writeln!(xml, "<pubDate>{}</pubDate>", item.published.to_rfc2822());
+            // RSS's own <author> must be an email address, so names go in dc:creator
+            for author in self.authors_of(item) {
+                let _ = writeln!(xml, "<dc:creator>{}</dc:creator>", escape_xml(author));
+            }
+            for tag in &item.tags {
+                let _ = writeln!(xml, "<category>{}</category>", escape_xml(tag));
+            }
+            if let Some(ref summary) = item.summary {
+                let _ = writeln!(xml, "<description>{}</description>", escape_xml(summary));
+            }
+            if let Some(ref html) = item.content_html {
+                let _ = writeln!(xml, "<content:encoded>{}</content:encoded>", escape_xml(html));
+            }
+            xml.push_str("</item>\n");
+        }
+
+        xml.push_str("</channel>\n</rss>\n");
+        xml
+    }
+
+    /// Render as Atom 1.0
+    pub fn to_atom(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">",
+            escape_xml(&self.language)
+        );
+        let _ = writeln!(xml, "<id>{}</id>", escape_xml(&self.feed_url));
+        let _ = writeln!(xml, "<title>{}</title>", escape_xml(&self.title));
+        let _ = writeln!(xml, "<subtitle>{}</subtitle>", escape_xml(&self.description));
+        let _ = writeln!(xml, "<updated>{}</updated>", self.updated().to_rfc3339());
+        let _ = writeln!(xml, "<link rel=\"self\" href=\"{}\"/>", escape_xml(&self.feed_url));
+        let _ = writeln!(xml, "<link rel=\"alternate\" href=\"{}\"/>", escape_xml(&self.home_page_url));
+        if let Some(ref author) = self.author {
+            let _ = writeln!(xml, "<author><name>{}</name></author>", escape_xml(author));
+        }
+
+        for item in &self.items {
+            xml.push_str("<entry>\n");
+            let _ = writeln!(xml, "<id>{}</id>", escape_xml(&item.id));
+            let _ = writeln!(xml, "<title>{}</title>", escape_xml(&item.title));
+            let _ = writeln!(xml, "<link rel=\"alternate\" href=\"{}\"/>", escape_xml(&item.url));
+            let _ = writeln!(xml, "<published>{}</published>", item.published.to_rfc3339());
+            let _ = writeln!(xml, "<updated>{}</updated>", item.last_modified().to_rfc3339());
+            for author in &item.authors {
+                let _ = writeln!(xml, "<author><name>{}</name></author>", escape_xml(author));
+            }
+            for tag in &item.tags {
+                let _ = writeln!(xml, "<category term=\"{}\"/>", escape_xml(tag));
+            }
+            if let Some(ref summary) = item.summary {
+                let _ = writeln!(xml, "<summary>{}</summary>", escape_xml(summary));
+            }
+            if let Some(ref html) = item.content_html {
+                let _ = writeln!(xml, "<content type=\"html\">{}</content>", escape_xml(html));
+            }
+            xml.push_str("</entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    /// Render as JSON Feed 1.1
+    pub fn to_json(&self) -> String {
+        let items: Vec<Value> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut entry = Map::new();
+                entry.insert("id".into(), json!(item.id));
+                entry.insert("url".into(), json!(item.url));
+                entry.insert("title".into(), json!(item.title));
+                if let Some(ref summary) = item.summary {
+                    entry.insert("summary".into(), json!(summary));
+                }
+                // JSON Feed requires some content, so summary feeds fall back to it
+                let content = item.content_html.clone().or_else(|| item.summary.clone()).unwrap_or_default();
+                entry.insert("content_html".into(), json!(content));
+                entry.insert("date_published".into(), json!(item.published.to_rfc3339()));
+                entry.insert("date_modified".into(), json!(item.last_modified().to_rfc3339()));
+                let authors: Vec<Value> = item.authors.iter().map(|name| json!({ "name": name })).collect();
+                if !authors.is_empty() {
+                    entry.insert("authors".into(), Value::Array(authors));
+                }
+                if !item.tags.is_empty() {
+                    entry.insert("tags".into(), json!(item.tags));
+                }
+                Value::Object(entry)
+            })
+            .collect();
+
+        let mut feed = json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": self.title,
+            "description": self.description,
+            "home_page_url": self.home_page_url,
+            "feed_url": self.feed_url,
+            "language": self.language,
+            "items": items,
+        });
+        if let Some(ref author) = self.author {
+            feed["authors"] = json!([{ "name": author }]);
+        }
+
+        serde_json::to_string_pretty(&feed).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed() -> Feed {
+        Feed {
+            title: "Notes & Essays".to_string(),
+            description: "Writing on <Rust>".to_string(),
+            home_page_url: "https://example.com".to_string(),
+            feed_url: "https://example.com/feed.xml".to_string(),
+            language: "en".to_string(),
+            author: Some("Site Owner".to_string()),
+            items: vec![
+                FeedItem {
+                    id: "tag:example.com,2025:article/pipelines".to_string(),
+                    url: "https://example.com/article/data/01-pipelines".to_string(),
+                    title: "Pipelines & Queues".to_string(),
+                    summary: Some("Moving data around".to_string()),
+                    content_html: Some("<p>Hello</p>".to_string()),
+                    published: ArticleDate::parse("2025-11-20T09:30:00+02:00").unwrap(),
+                    updated: Some(ArticleDate::parse("2025-11-22").unwrap()),
+                    authors: vec![],
+                    tags: vec!["rust".to_string()],
+                },
+                FeedItem {
+                    id: "tag:example.com,2025:article/intro".to_string(),
+                    url: "https://example.com/article/intro".to_string(),
+                    title: "Intro".to_string(),
+                    summary: None,
+                    content_html: None,
+                    published: ArticleDate::parse("2025-9-1").unwrap(),
+                    // Earlier than publication, so ignored
+                    updated: Some(ArticleDate::parse("2025-8-1").unwrap()),
+                    authors: vec!["Guest".to_string()],
+                    tags: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_rss_dates_guids_and_escaping() {
+        let rss = feed().to_rss();
+
+        assert!(rss.contains("<title>Notes &amp; Essays</title>"));
+        assert!(rss.contains("<description>Writing on &lt;Rust&gt;</description>"));
+        assert!(rss.contains("<pubDate>Thu, 20 Nov 2025 09:30:00 +0200</pubDate>"));
+        assert!(rss.contains("<pubDate>Mon, 1 Sep 2025 00:00:00 +0000</pubDate>"));
+        assert!(rss.contains("<lastBuildDate>Sat, 22 Nov 2025 00:00:00 +0000</lastBuildDate>"));
+        assert!(rss.contains("<guid isPermaLink=\"false\">tag:example.com,2025:article/pipelines</guid>"));
+        assert!(rss.contains("<content:encoded>&lt;p&gt;Hello&lt;/p&gt;</content:encoded>"));
+        // Items without authors fall back to the feed's
+        assert!(rss.contains("<dc:creator>Site Owner</dc:creator>"));
+        assert!(rss.contains("<dc:creator>Guest</dc:creator>"));
+    }
+
+    #[test]
+    fn test_atom_uses_rfc3339() {
+        let atom = feed().to_atom();
+
+        assert!(atom.contains("<updated>2025-11-22T00:00:00Z</updated>"));
+        assert!(atom.contains("<published>2025-11-20T09:30:00+02:00</published>"));
+        assert!(atom.contains("<published>2025-09-01T00:00:00Z</published>"));
+        assert!(atom.contains("<updated>2025-09-01T00:00:00Z</updated>"));
+        assert!(atom.contains("<content type=\"html\">&lt;p&gt;Hello&lt;/p&gt;</content>"));
+        assert!(atom.contains("<category term=\"rust\"/>"));
+    }
+
+    #[test]
+    fn test_json_feed() {
+        let json: Value = serde_json::from_str(&feed().to_json()).unwrap();
+
+        assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(json["items"][0]["id"], "tag:example.com,2025:article/pipelines");
+        assert_eq!(json["items"][0]["content_html"], "<p>Hello</p>");
+        assert_eq!(json["items"][0]["date_modified"], "2025-11-22T00:00:00Z");
+        assert_eq!(json["items"][1]["authors"][0]["name"], "Guest");
+        assert!(json["items"][1].get("summary").is_none());
+        assert_eq!(json["authors"][0]["name"], "Site Owner");
+    }
+}
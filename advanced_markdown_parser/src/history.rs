@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ArticleDate;
+
+/// Separates the fields of a `git log` header line
+const FIELD_SEP: char = '\u{1f}';
+/// Starts each commit in `git log` output
+const RECORD_SEP: char = '\u{1e}';
+
+/// A single commit that touched an article file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArticleRevision {
+    /// Full commit hash
+    pub commit: String,
+    /// Abbreviated commit hash for display
+    pub short_commit: String,
+    pub author: String,
+    pub date: ArticleDate,
+    /// First line of the commit message
+    pub message: String,
+    /// Unified diff of the article file in this commit
+    pub diff: String,
+}
+
+/// `git log` format for [`parse_revisions`]
+pub fn revision_format() -> String {
+    format!("--format={RECORD_SEP}%H{FIELD_SEP}%h{FIELD_SEP}%an{FIELD_SEP}%aI{FIELD_SEP}%s")
+}
+
+/// `git log --name-only` format for [`parse_last_commit_dates`]
+pub fn commit_date_format() -> String {
+    format!("--format={RECORD_SEP}%aI")
+}
+
+/// Parse `git log --patch` output written with [`revision_format`]
+///
+/// Diffs longer than `max_diff_lines` are cut short with a note of how much
+/// was left out. Records with a missing field or a bad date are skipped.
+pub fn parse_revisions(output: &str, max_diff_lines: usize) -> Vec<ArticleRevision> {
+    output
+        .split(RECORD_SEP)
+        .filter(|record| !record.trim().is_empty())
+        .filter_map(|record| parse_revision(record, max_diff_lines))
+        .collect()
+}
+
+/// One record: a header line of separated fields followed by the patch
+fn parse_revision(record: &str, max_diff_lines: usize) -> Option<ArticleRevision> {
+    let (header, patch) = record.split_once('\n').unwrap_or((record, ""));
+    let mut fields = header.split(FIELD_SEP);
+
+    let commit = fields.next()?.to_string();
+    let short_commit = fields.next()?.to_string();
+    let author = fields.next()?.to_string();
+    let date = ArticleDate::parse(fields.next()?).ok()?;
+    let message = fields.next().unwrap_or_default().to_string();
+
+    let patch = patch.trim();
+    let mut diff: String = patch
+        .lines()
+        .take(max_diff_lines)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let total_lines = patch.lines().count();
+    if total_lines > max_diff_lines {
+        diff.push_str(&format!("\n... {} more lines", total_lines - max_diff_lines));
+    }
+
+    Some(ArticleRevision {
+        commit,
+        short_commit,
+        author,
+        date,
+        message,
+        diff,
+    })
+}
+
+/// Parse `git log --name-only` output written with [`commit_date_format`]
+/// into the newest commit date of every file it names
+pub fn parse_last_commit_dates(output: &str) -> BTreeMap<String, ArticleDate> {
+    let mut dates = BTreeMap::new();
+
+    for record in output.split(RECORD_SEP) {
+        let mut lines = record.lines();
+        let Some(date) = lines.next().and_then(|line| ArticleDate::parse(line.trim()).ok()) else {
+            continue;
+        };
+        // Commits come newest first, so the first date seen for a file wins
+        for file in lines.filter(|line| !line.trim().is_empty()) {
+            dates.entry(file.to_string()).or_insert(date);
+        }
+    }
+
+    dates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_revisions() {
+        let output = format!(
+            "{r}abc123{f}abc{f}Jane Doe{f}2025-11-20T10:00:00+02:00{f}Fix typo\n\
+             diff --git a/a.md b/a.md\n-old\n+new\n\
+             {r}def456{f}def{f}John Doe{f}2025-11-01T09:00:00Z{f}Add article: part 1\n\
+             +line 1\n+line 2\n+line 3\n\
+             {r}bad{f}record\n\
+             {r}ghi789{f}ghi{f}No Date{f}not-a-date{f}Broken\n",
+            r = RECORD_SEP,
+            f = FIELD_SEP
+        );
+
+        let revisions = parse_revisions(&output, 2);
+
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].commit, "abc123");
+        assert_eq!(revisions[0].short_commit, "abc");
+        assert_eq!(revisions[0].author, "Jane Doe");
+        assert_eq!(revisions[0].date, ArticleDate::parse("2025-11-20T08:00:00Z").unwrap());
+        assert_eq!(revisions[0].message, "Fix typo");
+        assert_eq!(revisions[0].diff, "diff --git a/a.md b/a.md\n-old\n... 1 more lines");
+        assert_eq!(revisions[1].message, "Add article: part 1");
+        assert_eq!(revisions[1].diff, "+line 1\n+line 2\n... 1 more lines");
+
+        assert!(parse_revisions("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_parse_last_commit_dates() {
+        let output = format!(
+            "{r}2025-11-20T10:00:00Z\n\nSeries/01-intro.md\nnotes.md\n\
+             {r}2025-10-01T10:00:00Z\n\nSeries/01-intro.md\nProject Management/02-people.md\n\
+             {r}garbage\n\nignored.md\n",
+            r = RECORD_SEP
+        );
+
+        let dates = parse_last_commit_dates(&output);
+
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates["Series/01-intro.md"], ArticleDate::parse("2025-11-20T10:00:00Z").unwrap());
+        assert_eq!(dates["notes.md"], ArticleDate::parse("2025-11-20T10:00:00Z").unwrap());
+        assert_eq!(
+            dates["Project Management/02-people.md"],
+            ArticleDate::parse("2025-10-01T10:00:00Z").unwrap()
+        );
+        assert!(!dates.contains_key("ignored.md"));
+    }
+}
@@ -0,0 +1,89 @@
+/// Language assumed for articles without a language suffix
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Whether `tag` looks like a language code such as `fr`, `zu` or `pt-BR`
+pub fn is_language_tag(tag: &str) -> bool {
+    let (primary, region) = match tag.split_once('-') {
+        Some((primary, region)) => (primary, Some(region)),
+        None => (tag, None),
+    };
+
+    let primary_ok = (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_lowercase());
+    let region_ok = region.is_none_or(|region| {
+        (2..=4).contains(&region.len()) && region.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+
+    primary_ok && region_ok
+}
+
+/// Split a translation suffix off a file stem
+///
+/// `"01-introduction.fr"` becomes `("01-introduction", Some("fr"))` when `fr`
+/// is one of the site's `languages`. Any other stem, including ones whose
+/// suffix merely looks like a language (`notes.api`, `intro.io`), is returned
+/// unchanged with `None`.
+pub fn split_language_suffix<'a>(stem: &'a str, languages: &[String]) -> (&'a str, Option<&'a str>) {
+    match stem.rsplit_once('.') {
+        Some((base, suffix))
+            if !base.is_empty() && languages.iter().any(|lang| lang.eq_ignore_ascii_case(suffix)) =>
+        {
+            (base, Some(suffix))
+        }
+        _ => (stem, None),
+    }
+}
+
+/// Pick the best match for `locale` from the available languages
+///
+/// Tries an exact match, then the primary subtag (`pt-BR` -> `pt`), then
+/// [`DEFAULT_LANGUAGE`], and finally the first available language.
+pub fn negotiate_language<'a>(locale: Option<&str>, available: &[&'a str]) -> Option<&'a str> {
+    let find = |wanted: &str| {
+        available
+            .iter()
+            .copied()
+            .find(|lang| lang.eq_ignore_ascii_case(wanted))
+    };
+
+    locale
+        .and_then(|locale| {
+            find(locale).or_else(|| locale.split('-').next().and_then(find))
+        })
+        .or_else(|| find(DEFAULT_LANGUAGE))
+        .or_else(|| available.first().copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_language_suffix() {
+        let languages = ["en", "fr", "pt-BR"].map(String::from);
+
+        assert_eq!(split_language_suffix("01-introduction.fr", &languages), ("01-introduction", Some("fr")));
+        assert_eq!(split_language_suffix("01-introduction.pt-BR", &languages), ("01-introduction", Some("pt-BR")));
+        assert_eq!(split_language_suffix("01-introduction", &languages), ("01-introduction", None));
+        // Version numbers, long words and unconfigured codes are not translations
+        assert_eq!(split_language_suffix("release-1.0", &languages), ("release-1.0", None));
+        assert_eq!(split_language_suffix("notes.draft", &languages), ("notes.draft", None));
+        assert_eq!(split_language_suffix("notes.api", &languages), ("notes.api", None));
+        assert_eq!(split_language_suffix("intro.io", &languages), ("intro.io", None));
+        assert_eq!(split_language_suffix(".fr", &languages), (".fr", None));
+
+        assert!(is_language_tag("pt-BR") && is_language_tag("zu"));
+        assert!(!is_language_tag("draft") && !is_language_tag("1.0"));
+    }
+
+    #[test]
+    fn test_negotiate_language() {
+        let available = ["en", "fr", "pt"];
+
+        assert_eq!(negotiate_language(Some("fr"), &available), Some("fr"));
+        assert_eq!(negotiate_language(Some("pt-BR"), &available), Some("pt"));
+        assert_eq!(negotiate_language(Some("zu"), &available), Some("en"));
+        assert_eq!(negotiate_language(None, &available), Some("en"));
+        assert_eq!(negotiate_language(Some("zu"), &["fr"]), Some("fr"));
+    }
+}
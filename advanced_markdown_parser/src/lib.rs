@@ -1,5 +1,29 @@
 use serde::{Deserialize, Serialize};
 
+mod article_id;
+mod date;
+mod excerpt;
+mod feed;
+mod history;
+mod language;
+mod lint;
+mod reading;
+mod sitemap;
+mod search;
+mod taxonomy;
+
+pub use article_id::{ArticleId, ArticlePathError};
+pub use date::{ArticleDate, DateParseError};
+pub use excerpt::excerpt;
+pub use feed::{Feed, FeedContent, FeedItem};
+pub use history::{commit_date_format, parse_last_commit_dates, parse_revisions, revision_format, ArticleRevision};
+pub use language::{is_language_tag, negotiate_language, split_language_suffix, DEFAULT_LANGUAGE};
+pub use lint::{lint_articles, LintFinding, LintReport, LintRule, LintSource};
+pub use reading::{import_reading_csv, Book, ReadingImportError, ReadingList, ReadingStatus};
+pub use sitemap::{encode_segment, render_robots, render_sitemap, SitemapEntry};
+pub use search::{SearchDocument, SearchFilters, SearchIndex, SearchResult, SnippetPart};
+pub use taxonomy::{near_duplicate_terms, CategoryDefinition, TagDefinition, Taxonomy};
+
 /// A reference/resource link for the references tab
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Reference {
@@ -28,10 +52,22 @@ pub struct ArticleSeries {
 /// TOML metadata extracted from markdown front matter
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct ArticleTomlMetadata {
+    /// Permanent identifier used for feed GUIDs; keeps them stable when the
+    /// file is renamed or moved (defaults to the article's path)
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Publication date (`YYYY-MM-DD` or RFC 3339)
     #[serde(default)]
-    pub date: Option<String>,
+    pub date: Option<ArticleDate>,
+    /// Last revision date; filled from the file's mtime when not set
+    #[serde(default)]
+    pub updated: Option<ArticleDate>,
+    /// Free-text author name (prefer `authors`)
     #[serde(default)]
     pub author: Option<String>,
+    /// Author IDs from the site's `authors.toml` registry
+    #[serde(default)]
+    pub authors: Vec<String>,
     #[serde(default)]
     pub summary: Option<String>,
     #[serde(default)]
@@ -71,12 +107,69 @@ pub struct ArticleTomlMetadata {
     pub show_related: bool,
     #[serde(default)]
     pub show_quiz: bool,
+    #[serde(default = "default_true")]
+    pub show_history: bool,
+    /// Work in progress; kept out of feeds and the sitemap
+    #[serde(default)]
+    pub draft: bool,
+    /// Reachable by URL but kept out of feeds and the sitemap
+    #[serde(default)]
+    pub unlisted: bool,
+}
+
+impl ArticleTomlMetadata {
+    /// Whether the article may be advertised to feed readers and crawlers
+    pub fn is_listed(&self) -> bool {
+        !self.draft && !self.unlisted
+    }
+
+    /// Every series the article belongs to: the folder series, `series` and
+    /// `[[article_series]]`, without duplicates
+    pub fn series_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        let all = self
+            .primary_series
+            .iter()
+            .chain(&self.series)
+            .chain(self.article_series.iter().map(|entry| &entry.name));
+        for name in all {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        names
+    }
 }
 
 fn default_true() -> bool {
     true
 }
 
+/// How serious a front matter problem is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiagnosticSeverity {
+    Warning,
+    Error,
+}
+
+/// A problem found while reading front matter
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetadataDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// The front matter field the problem relates to, if any
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl std::fmt::Display for MetadataDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
 /// Result of parsing markdown with front matter
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParsedMarkdown {
@@ -84,6 +177,8 @@ pub struct ParsedMarkdown {
     pub metadata: Option<ArticleTomlMetadata>,
     /// The markdown content without the front matter delimiters
     pub content: String,
+    /// Problems found in the front matter (invalid TOML, bad dates, ...)
+    pub diagnostics: Vec<MetadataDiagnostic>,
 }
 
 /// Parse TOML metadata from markdown content
@@ -101,6 +196,8 @@ pub struct ParsedMarkdown {
 pub fn parse_markdown_with_metadata(content: &str) -> ParsedMarkdown {
     const DELIMITER: &str = "#####";
 
+    let mut diagnostics = Vec::new();
+
     // Try to find and parse TOML metadata
     let metadata = if let Some(first_delimiter_pos) = content.find(DELIMITER) {
         let after_first = &content[first_delimiter_pos + DELIMITER.len()..];
@@ -108,8 +205,7 @@ pub fn parse_markdown_with_metadata(content: &str) -> ParsedMarkdown {
         if let Some(second_delimiter_pos) = after_first.find(DELIMITER) {
             let toml_content = after_first[..second_delimiter_pos].trim();
 
-            // Parse TOML
-            toml::from_str::<ArticleTomlMetadata>(toml_content).ok()
+            parse_toml_metadata(toml_content, &mut diagnostics)
         } else {
             None
         }
@@ -120,7 +216,76 @@ pub fn parse_markdown_with_metadata(content: &str) -> ParsedMarkdown {
     // Extract content without metadata delimiters
     let content = extract_content_without_metadata(content);
 
-    ParsedMarkdown { metadata, content }
+    ParsedMarkdown {
+        metadata,
+        content,
+        diagnostics,
+    }
+}
+
+/// Front matter fields holding an [`ArticleDate`]
+const DATE_FIELDS: [&str; 2] = ["date", "updated"];
+
+/// Parse the TOML between the delimiters, dropping invalid dates instead of
+/// discarding the whole block
+fn parse_toml_metadata(
+    toml_content: &str,
+    diagnostics: &mut Vec<MetadataDiagnostic>,
+) -> Option<ArticleTomlMetadata> {
+    let mut table = match toml::from_str::<toml::Table>(toml_content) {
+        Ok(table) => table,
+        Err(e) => {
+            diagnostics.push(MetadataDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                field: None,
+                message: format!("front matter is not valid TOML: {}", e.message()),
+            });
+            return None;
+        }
+    };
+
+    for field in DATE_FIELDS {
+        let Some(value) = table.remove(field) else {
+            continue;
+        };
+
+        // Unquoted TOML dates are accepted as well as strings
+        let raw = match value {
+            toml::Value::String(raw) => raw,
+            toml::Value::Datetime(datetime) => datetime.to_string(),
+            other => {
+                diagnostics.push(MetadataDiagnostic {
+                    severity: DiagnosticSeverity::Error,
+                    field: Some(field.to_string()),
+                    message: format!("expected a date string, found {}", other.type_str()),
+                });
+                continue;
+            }
+        };
+
+        match ArticleDate::parse(&raw) {
+            Ok(date) => {
+                table.insert(field.to_string(), toml::Value::String(date.to_string()));
+            }
+            Err(e) => diagnostics.push(MetadataDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                field: Some(field.to_string()),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    match table.try_into::<ArticleTomlMetadata>() {
+        Ok(metadata) => Some(metadata),
+        Err(e) => {
+            diagnostics.push(MetadataDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                field: None,
+                message: format!("front matter has invalid fields: {}", e.message()),
+            });
+            None
+        }
+    }
 }
 
 /// Extract content without metadata delimiters
@@ -163,7 +328,7 @@ Some content here.
 
         assert!(parsed.metadata.is_some());
         let metadata = parsed.metadata.unwrap();
-        assert_eq!(metadata.date, Some("2025-11-21".to_string()));
+        assert_eq!(metadata.date, Some(ArticleDate::parse("2025-11-21").unwrap()));
         assert_eq!(metadata.author, Some("John Doe".to_string()));
         assert_eq!(metadata.summary, Some("A test article".to_string()));
         assert!(parsed.content.contains("# Test Article"));
@@ -233,5 +398,51 @@ next = "article-3"
         assert_eq!(metadata.article_series[0].name, "My Series");
         assert_eq!(metadata.article_series[0].prev, Some("article-1".to_string()));
         assert_eq!(metadata.article_series[0].next, Some("article-3".to_string()));
+        assert_eq!(metadata.series_names(), ["My Series"]);
+    }
+
+    #[test]
+    fn test_series_names() {
+        let markdown = "#####\nseries = [\"Rust\", \"Storage\"]\n[[article_series]]\nname = \"Storage\"\n\
+                        [[article_series]]\nname = \"Netabase\"\n#####\n# Part 2\n";
+        let mut metadata = parse_markdown_with_metadata(markdown).metadata.unwrap();
+        metadata.primary_series = Some("Rust".to_string());
+
+        assert_eq!(metadata.series_names(), ["Rust", "Storage", "Netabase"]);
+    }
+
+    #[test]
+    fn test_invalid_date_is_reported_and_dropped() {
+        let markdown = r#"#####
+date = "2025-02-30"
+updated = "2025-11-20T10:00:00Z"
+author = "Jane Doe"
+#####
+
+# Dated Article
+"#;
+
+        let parsed = parse_markdown_with_metadata(markdown);
+
+        let metadata = parsed.metadata.unwrap();
+        assert_eq!(metadata.date, None);
+        assert_eq!(metadata.updated, Some(ArticleDate::parse("2025-11-20T10:00:00Z").unwrap()));
+        assert_eq!(metadata.author, Some("Jane Doe".to_string()));
+        assert_eq!(parsed.diagnostics.len(), 1);
+        assert_eq!(parsed.diagnostics[0].field, Some("date".to_string()));
+        assert_eq!(parsed.diagnostics[0].severity, DiagnosticSeverity::Error);
+    }
+
+    #[test]
+    fn test_unquoted_toml_date() {
+        let markdown = r#"#####
+date = 2025-09-01
+#####
+"#;
+
+        let parsed = parse_markdown_with_metadata(markdown);
+
+        assert_eq!(parsed.metadata.unwrap().date, Some(ArticleDate::parse("2025-9-1").unwrap()));
+        assert!(parsed.diagnostics.is_empty());
     }
 }
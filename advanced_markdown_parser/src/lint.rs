@@ -0,0 +1,463 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{parse_markdown_with_metadata, split_language_suffix, ArticleTomlMetadata, DiagnosticSeverity, Taxonomy};
+
+/// What a lint finding is about; serialized in kebab-case for tooling
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// Front matter that is missing, isn't valid TOML or has invalid fields
+    FrontMatter,
+    /// A series folder without a usable `summary.md`
+    MissingSummary,
+    /// A site-level file such as `taxonomy.toml` that fails to load
+    Manifest,
+    /// No `# ` heading to take the title from
+    MissingTitle,
+    /// Two articles that would share a slug or an `id`
+    DuplicateSlug,
+    /// A `prev`/`next` link to a missing article, or one that doesn't link back
+    SeriesLink,
+    /// A reference without a URL
+    EmptyReference,
+    /// A tag, topic or category the taxonomy doesn't know
+    UnknownTerm,
+    /// A local image that doesn't exist
+    MissingImage,
+    /// A dated, listed article without an `id`, whose feed GUID would change
+    /// if the file were renamed
+    MissingId,
+}
+
+/// One problem found by the linter
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LintFinding {
+    /// File the problem is in, relative to the content root
+    pub path: String,
+    pub rule: LintRule,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+}
+
+impl LintFinding {
+    pub fn error(path: &str, rule: LintRule, message: String) -> Self {
+        LintFinding {
+            path: path.to_string(),
+            rule,
+            severity: DiagnosticSeverity::Error,
+            message,
+        }
+    }
+
+    pub fn warning(path: &str, rule: LintRule, message: String) -> Self {
+        LintFinding {
+            path: path.to_string(),
+            rule,
+            severity: DiagnosticSeverity::Warning,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            DiagnosticSeverity::Error => "error",
+            DiagnosticSeverity::Warning => "warning",
+        };
+        write!(f, "{}: {}[{}]: {}", self.path, severity, rule_name(self.rule), self.message)
+    }
+}
+
+fn rule_name(rule: LintRule) -> String {
+    serde_json::to_value(rule)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Everything the linter found, with totals for gating on
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LintReport {
+    /// Number of markdown files checked
+    pub files: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    /// Total the findings, ordered by path
+    pub fn new(files: usize, mut findings: Vec<LintFinding>) -> Self {
+        findings.sort_by(|a, b| a.path.cmp(&b.path));
+        let errors = findings
+            .iter()
+            .filter(|f| f.severity == DiagnosticSeverity::Error)
+            .count();
+
+        LintReport {
+            files,
+            errors,
+            warnings: findings.len() - errors,
+            findings,
+        }
+    }
+
+    /// No errors; warnings alone don't fail a report
+    pub fn passed(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+/// A markdown file to lint
+#[derive(Clone, Debug)]
+pub struct LintSource {
+    /// Path relative to the content root, with `/` separators (`Series/02-people.md`)
+    pub path: String,
+    pub content: String,
+}
+
+/// An article's parsed front matter and the keys it is identified by
+struct Linted<'a> {
+    path: &'a str,
+    /// Path without `.md`, as used in `prev`/`next` links
+    link: &'a str,
+    /// Translation base, shared by every language version
+    base: &'a str,
+    lang: Option<&'a str>,
+    metadata: ArticleTomlMetadata,
+}
+
+/// Check articles one by one and against each other
+///
+/// Covers front matter, titles, references, taxonomy terms, local images,
+/// duplicate slugs and `id`s, and series `prev`/`next` links.
+/// `image_exists(path, src)` is asked about every image that isn't a remote
+/// URL, with `path` being the article that references it.
+pub fn lint_articles(
+    sources: &[LintSource],
+    taxonomy: &Taxonomy,
+    languages: &[String],
+    image_exists: impl Fn(&str, &str) -> bool,
+) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let mut linted = Vec::new();
+
+    for source in sources {
+        let path = source.path.as_str();
+        let parsed = parse_markdown_with_metadata(&source.content);
+
+        findings.extend(parsed.diagnostics.iter().map(|diagnostic| LintFinding {
+            path: path.to_string(),
+            rule: LintRule::FrontMatter,
+            severity: diagnostic.severity,
+            message: diagnostic.to_string(),
+        }));
+
+        // The same rule the index uses to pick the title
+        if !parsed.content.lines().any(|line| line.trim().starts_with("# ")) {
+            findings.push(LintFinding::error(
+                path,
+                LintRule::MissingTitle,
+                "no `# ` heading to use as the title".to_string(),
+            ));
+        }
+
+        for src in image_sources(&parsed.content) {
+            if is_local(&src) && !image_exists(path, &src) {
+                findings.push(LintFinding::error(
+                    path,
+                    LintRule::MissingImage,
+                    format!("image \"{}\" does not exist", src),
+                ));
+            }
+        }
+
+        let Some(mut metadata) = parsed.metadata else {
+            if parsed.diagnostics.is_empty() {
+                findings.push(LintFinding::warning(path, LintRule::FrontMatter, "no front matter".to_string()));
+            }
+            continue;
+        };
+
+        findings.extend(taxonomy.normalize(&mut metadata).into_iter().map(|diagnostic| LintFinding {
+            path: path.to_string(),
+            rule: LintRule::UnknownTerm,
+            severity: diagnostic.severity,
+            message: diagnostic.to_string(),
+        }));
+
+        for reference in &metadata.references {
+            if reference.url.trim().is_empty() {
+                findings.push(LintFinding::error(
+                    path,
+                    LintRule::EmptyReference,
+                    format!("reference \"{}\" has no URL", reference.title),
+                ));
+            }
+        }
+
+        if let Some(ref thumbnail) = metadata.thumbnail
+            && is_local(thumbnail)
+            && !image_exists(path, thumbnail)
+        {
+            findings.push(LintFinding::error(
+                path,
+                LintRule::MissingImage,
+                format!("thumbnail \"{}\" does not exist", thumbnail),
+            ));
+        }
+
+        if metadata.id.is_none() && metadata.date.is_some() && metadata.is_listed() {
+            findings.push(LintFinding::warning(
+                path,
+                LintRule::MissingId,
+                "no `id`, so renaming the file changes its feed GUID".to_string(),
+            ));
+        }
+
+        let link = path.strip_suffix(".md").unwrap_or(path);
+        let (base, lang) = split_language_suffix(link, languages);
+        linted.push(Linted { path, link, base, lang, metadata });
+    }
+
+    check_duplicates(&linted, &mut findings);
+    check_series_links(&linted, &mut findings);
+    findings
+}
+
+/// Slug of an article: its file name without part number, language or
+/// extension, lower-cased (`Series/02-People.fr.md` -> `people`)
+fn slug(base: &str) -> String {
+    let name = base.rsplit('/').next().unwrap_or(base);
+    let name = match name.split_once('-') {
+        Some((number, rest)) if !rest.is_empty() && number.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => name,
+    };
+    name.to_lowercase()
+}
+
+/// Articles in the same folder and language sharing a slug, and different
+/// articles sharing an `id` (translations share theirs)
+fn check_duplicates(linted: &[Linted], findings: &mut Vec<LintFinding>) {
+    let mut slugs: BTreeMap<(&str, String, Option<&str>), &str> = BTreeMap::new();
+    let mut ids: BTreeMap<&str, &Linted> = BTreeMap::new();
+
+    for article in linted {
+        let folder = article.base.rsplit_once('/').map(|(folder, _)| folder).unwrap_or("");
+        match slugs.get(&(folder, slug(article.base), article.lang)) {
+            Some(first) => findings.push(LintFinding::error(
+                article.path,
+                LintRule::DuplicateSlug,
+                format!("slug \"{}\" is already used by {}", slug(article.base), first),
+            )),
+            None => {
+                slugs.insert((folder, slug(article.base), article.lang), article.path);
+            }
+        }
+
+        let Some(ref id) = article.metadata.id else {
+            continue;
+        };
+        match ids.get(id.as_str()) {
+            Some(first) if first.base != article.base => findings.push(LintFinding::error(
+                article.path,
+                LintRule::DuplicateSlug,
+                format!("id \"{}\" is already used by {}", id, first.path),
+            )),
+            Some(_) => {}
+            None => {
+                ids.insert(id, article);
+            }
+        }
+    }
+}
+
+/// `prev`/`next` links must point at an article, and an article that is
+/// linked to should link back in the same series
+///
+/// Only the default-language version is checked for links back, since
+/// translations point at the default-language parts.
+fn check_series_links(linted: &[Linted], findings: &mut Vec<LintFinding>) {
+    let by_link: BTreeMap<&str, &Linted> = linted.iter().map(|article| (article.link, article)).collect();
+
+    for article in linted {
+        let metadata = &article.metadata;
+        let legacy = [("prev_article", &metadata.prev_article), ("next_article", &metadata.next_article)];
+        for (field, target) in legacy {
+            if let Some(target) = target
+                && !by_link.contains_key(target.as_str())
+            {
+                findings.push(LintFinding::error(
+                    article.path,
+                    LintRule::SeriesLink,
+                    format!("{} points to \"{}\", which does not exist", field, target),
+                ));
+            }
+        }
+
+        for entry in &metadata.article_series {
+            for (field, target, back) in [("prev", &entry.prev, "next"), ("next", &entry.next, "prev")] {
+                let Some(target) = target else {
+                    continue;
+                };
+                let Some(linked) = by_link.get(target.as_str()) else {
+                    findings.push(LintFinding::error(
+                        article.path,
+                        LintRule::SeriesLink,
+                        format!(
+                            "{} in series \"{}\" points to \"{}\", which does not exist",
+                            field, entry.name, target
+                        ),
+                    ));
+                    continue;
+                };
+                if article.lang.is_some() {
+                    continue;
+                }
+
+                let linked_entry = linked.metadata.article_series.iter().find(|e| e.name == entry.name);
+                let link_back = linked_entry.and_then(|e| match back {
+                    "next" => e.next.as_deref(),
+                    _ => e.prev.as_deref(),
+                });
+                if link_back != Some(article.link) {
+                    let found = match (linked_entry, link_back) {
+                        (None, _) => format!("which has no [[article_series]] entry for \"{}\"", entry.name),
+                        (Some(_), None) => format!("which has no {}", back),
+                        (Some(_), Some(other)) => format!("whose {} is \"{}\"", back, other),
+                    };
+                    findings.push(LintFinding::warning(
+                        article.path,
+                        LintRule::SeriesLink,
+                        format!("{} in series \"{}\" points to \"{}\", {}", field, entry.name, target, found),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Sources of the markdown images (`![alt](src "title")`) outside code blocks
+fn image_sources(markdown: &str) -> Vec<String> {
+    let mut sources = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("![") {
+            rest = &rest[start + 2..];
+            let Some(close) = rest.find("](") else {
+                break;
+            };
+            let after = &rest[close + 2..];
+            let Some(end) = after.find(')') else {
+                break;
+            };
+
+            // Drop an optional title after the URL
+            let target = after[..end].split_whitespace().next().unwrap_or_default();
+            if !target.is_empty() {
+                sources.push(target.trim_matches(|c| c == '<' || c == '>').to_string());
+            }
+            rest = &after[end..];
+        }
+    }
+
+    sources
+}
+
+/// Whether an image is served by the site rather than fetched from elsewhere
+fn is_local(src: &str) -> bool {
+    !(src.contains("://") || src.starts_with("//") || src.starts_with("data:"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_LANGUAGE;
+
+    fn source(path: &str, content: &str) -> LintSource {
+        LintSource {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    fn rules(findings: &[LintFinding]) -> Vec<(&str, LintRule)> {
+        findings.iter().map(|f| (f.path.as_str(), f.rule)).collect()
+    }
+
+    #[test]
+    fn test_lint_reports_article_problems() {
+        let sources = [
+            source("broken.md", "#####\ndate = \"2025-13-01\"\n#####\n# Broken\n"),
+            source("untitled.md", "#####\nsummary = \"x\"\n#####\nJust text\n"),
+            source(
+                "refs.md",
+                "#####\nthumbnail = \"/missing.png\"\n\n[[references]]\ntitle = \"Nothing\"\nurl = \" \"\n#####\n# Refs\n\n\
+                 ![ok](/present.png) ![gone](images/gone.png \"Gone\") ![remote](https://example.com/a.png)\n\
+                 ```rust\nlet v = vec![a](b);\n```\n",
+            ),
+            source("dated.md", "#####\ndate = \"2025-11-20\"\n#####\n# Dated\n"),
+            source("dated-id.md", "#####\nid = \"dated\"\ndate = \"2025-11-20\"\n#####\n# Dated\n"),
+            source("dated-draft.md", "#####\ndate = \"2025-11-20\"\ndraft = true\n#####\n# Draft\n"),
+        ];
+
+        let languages = [DEFAULT_LANGUAGE.to_string()];
+        let findings = lint_articles(&sources, &Taxonomy::default(), &languages, |_, src| src == "/present.png");
+
+        assert_eq!(
+            rules(&findings),
+            [
+                ("broken.md", LintRule::FrontMatter),
+                ("untitled.md", LintRule::MissingTitle),
+                ("refs.md", LintRule::MissingImage),
+                ("refs.md", LintRule::EmptyReference),
+                ("refs.md", LintRule::MissingImage),
+                ("dated.md", LintRule::MissingId),
+            ]
+        );
+        assert_eq!(findings[2].message, "image \"images/gone.png\" does not exist");
+        assert_eq!(findings[4].message, "thumbnail \"/missing.png\" does not exist");
+    }
+
+    #[test]
+    fn test_lint_checks_slugs_and_series_links() {
+        let sources = [
+            source("S/01-intro.md", "#####\nid = \"intro\"\n[[article_series]]\nname = \"S\"\nnext = \"S/02-more\"\n#####\n# One\n"),
+            source("S/01-intro.fr.md", "#####\nid = \"intro\"\n[[article_series]]\nname = \"S\"\nnext = \"S/02-more\"\n#####\n# Un\n"),
+            source("S/02-more.md", "#####\n[[article_series]]\nname = \"S\"\nprev = \"S/01-intro\"\nnext = \"S/03-gone\"\n#####\n# Two\n"),
+            source("S/03-Intro.md", "#####\nid = \"intro\"\n[[article_series]]\nname = \"S\"\nprev = \"S/01-intro\"\n#####\n# Three\n"),
+        ];
+
+        let languages = ["en", "fr"].map(String::from);
+        let findings = lint_articles(&sources, &Taxonomy::default(), &languages, |_, _| true);
+        let messages: Vec<String> = findings.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            messages,
+            [
+                "S/03-Intro.md: error[duplicate-slug]: slug \"intro\" is already used by S/01-intro.md",
+                "S/03-Intro.md: error[duplicate-slug]: id \"intro\" is already used by S/01-intro.md",
+                "S/02-more.md: error[series-link]: next in series \"S\" points to \"S/03-gone\", which does not exist",
+                "S/03-Intro.md: warning[series-link]: prev in series \"S\" points to \"S/01-intro\", whose next is \"S/02-more\"",
+            ]
+        );
+
+        let report = LintReport::new(sources.len(), findings);
+        assert_eq!((report.errors, report.warnings, report.passed()), (3, 1, false));
+        assert_eq!(report.findings[0].path, "S/02-more.md");
+    }
+}
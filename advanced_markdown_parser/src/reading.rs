@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ArticleDate;
+
+/// Where a book is on the reading list; serialized with the shelf names
+/// Goodreads and StoryGraph use
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReadingStatus {
+    CurrentlyReading,
+    Read,
+    #[default]
+    #[serde(alias = "to-read")]
+    WantToRead,
+    DidNotFinish,
+}
+
+impl ReadingStatus {
+    /// Every status, in the order the reading page offers them
+    pub const ALL: [ReadingStatus; 4] = [
+        ReadingStatus::CurrentlyReading,
+        ReadingStatus::Read,
+        ReadingStatus::WantToRead,
+        ReadingStatus::DidNotFinish,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReadingStatus::CurrentlyReading => "Currently Reading",
+            ReadingStatus::Read => "Read",
+            ReadingStatus::WantToRead => "Want to Read",
+            ReadingStatus::DidNotFinish => "Did Not Finish",
+        }
+    }
+
+    /// A Goodreads or StoryGraph shelf name (`to-read`, `currently-reading`, ...)
+    fn from_shelf(shelf: &str) -> Option<Self> {
+        match shelf.trim() {
+            "currently-reading" => Some(ReadingStatus::CurrentlyReading),
+            "read" => Some(ReadingStatus::Read),
+            "to-read" | "want-to-read" => Some(ReadingStatus::WantToRead),
+            "did-not-finish" => Some(ReadingStatus::DidNotFinish),
+            _ => None,
+        }
+    }
+}
+
+/// A book on the reading list
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Book {
+    pub title: String,
+    pub author: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub status: ReadingStatus,
+    /// ISBN-10 or ISBN-13, digits only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isbn: Option<String>,
+    /// Cover image URL (defaults to Open Library's cover for the ISBN)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<ArticleDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished: Option<ArticleDate>,
+    /// Out of 5; half and quarter stars are kept
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<f32>,
+    /// Personal notes shown with the book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Path of an article reviewing the book (`Reviews/ddia`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<String>,
+    /// External page for the book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+}
+
+impl Book {
+    fn new(title: String, author: String, status: ReadingStatus) -> Self {
+        Book {
+            title,
+            author,
+            description: None,
+            status,
+            isbn: None,
+            cover: None,
+            started: None,
+            finished: None,
+            rating: None,
+            notes: None,
+            review: None,
+            link: None,
+        }
+    }
+
+    /// `cover`, or the Open Library cover for the ISBN
+    pub fn cover_url(&self) -> Option<String> {
+        self.cover.clone().or_else(|| {
+            self.isbn
+                .as_ref()
+                .map(|isbn| format!("https://covers.openlibrary.org/b/isbn/{}-M.jpg", isbn))
+        })
+    }
+
+    /// Whether `other` is the same book: by ISBN when both have one,
+    /// otherwise by title and author
+    fn same_book(&self, other: &Book) -> bool {
+        match (&self.isbn, &other.isbn) {
+            (Some(a), Some(b)) => a == b,
+            _ => {
+                match_key(&self.title) == match_key(&other.title)
+                    && match_key(&self.author) == match_key(&other.author)
+            }
+        }
+    }
+
+    /// Take what an export knows from `imported`; covers, notes, review links
+    /// and descriptions added by hand are kept
+    fn update_from(&mut self, imported: Book) {
+        self.status = imported.status;
+        self.isbn = self.isbn.take().or(imported.isbn);
+        self.started = imported.started.or(self.started);
+        self.finished = imported.finished.or(self.finished);
+        self.rating = imported.rating.or(self.rating);
+        self.notes = self.notes.take().or(imported.notes);
+    }
+}
+
+/// Lower-case letters and digits only, for matching titles and authors
+fn match_key(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The books in `reading.toml`, in the order they are listed
+///
+/// ```toml
+/// [[books]]
+/// title = "Designing Data-Intensive Applications"
+/// author = "Martin Kleppmann"
+/// status = "currently-reading"
+/// isbn = "9781449373320"
+/// started = "2025-10-02"
+/// review = "Reviews/ddia"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReadingList {
+    #[serde(default)]
+    pub books: Vec<Book>,
+}
+
+impl ReadingList {
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Books with `status`, or all of them
+    pub fn filter(&self, status: Option<ReadingStatus>) -> Vec<Book> {
+        self.books
+            .iter()
+            .filter(|book| status.is_none_or(|status| book.status == status))
+            .cloned()
+            .collect()
+    }
+
+    /// Add imported books, updating the ones already listed
+    ///
+    /// Returns how many were added and how many updated.
+    pub fn merge(&mut self, imported: Vec<Book>) -> (usize, usize) {
+        let (mut added, mut updated) = (0, 0);
+        for book in imported {
+            match self.books.iter_mut().find(|existing| existing.same_book(&book)) {
+                Some(existing) => {
+                    existing.update_from(book);
+                    updated += 1;
+                }
+                None => {
+                    self.books.push(book);
+                    added += 1;
+                }
+            }
+        }
+        (added, updated)
+    }
+}
+
+/// Why a CSV export couldn't be imported
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReadingImportError {
+    /// The header matches neither a Goodreads nor a StoryGraph export
+    UnknownFormat,
+    /// A column the detected format needs is missing
+    MissingColumn(&'static str),
+}
+
+impl std::fmt::Display for ReadingImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadingImportError::UnknownFormat => {
+                write!(f, "not a Goodreads or StoryGraph export: no \"Exclusive Shelf\" or \"Read Status\" column")
+            }
+            ReadingImportError::MissingColumn(column) => write!(f, "missing column \"{}\"", column),
+        }
+    }
+}
+
+impl std::error::Error for ReadingImportError {}
+
+/// Read a Goodreads or StoryGraph library export, telling them apart by
+/// their header
+///
+/// Goodreads "Private Notes" stay out of the list, which is published.
+pub fn import_reading_csv(data: &str) -> Result<Vec<Book>, ReadingImportError> {
+    let mut records = parse_csv(data).into_iter();
+    let header = records.next().unwrap_or_default();
+    let columns: HashMap<&str, usize> = header.iter().enumerate().map(|(i, name)| (name.trim(), i)).collect();
+
+    let column = |name: &'static str| columns.get(name).copied().ok_or(ReadingImportError::MissingColumn(name));
+    let optional = |name: &str| columns.get(name).copied();
+
+    let goodreads = columns.contains_key("Exclusive Shelf");
+    if !goodreads && !columns.contains_key("Read Status") {
+        return Err(ReadingImportError::UnknownFormat);
+    }
+
+    let title = column("Title")?;
+    let mut books = Vec::new();
+
+    if goodreads {
+        let (author, shelf) = (column("Author")?, column("Exclusive Shelf")?);
+        let (isbn, isbn13) = (optional("ISBN"), optional("ISBN13"));
+        let (rating, read, review) = (optional("My Rating"), optional("Date Read"), optional("My Review"));
+
+        for record in records {
+            let field = |i: Option<usize>| i.and_then(|i| record.get(i)).map(|v| v.trim()).filter(|v| !v.is_empty());
+            let Some(title) = field(Some(title)) else {
+                continue;
+            };
+            let status = field(Some(shelf)).and_then(ReadingStatus::from_shelf).unwrap_or_default();
+
+            let mut book = Book::new(title.to_string(), field(Some(author)).unwrap_or_default().to_string(), status);
+            book.isbn = field(isbn13).and_then(clean_isbn).or_else(|| field(isbn).and_then(clean_isbn));
+            book.finished = field(read).and_then(export_date);
+            // Goodreads writes 0 for "not rated"
+            book.rating = field(rating).and_then(|r| r.parse().ok()).filter(|r: &f32| *r > 0.0);
+            book.notes = field(review).map(str::to_string);
+            books.push(book);
+        }
+    } else {
+        let (authors, status) = (column("Authors")?, column("Read Status")?);
+        let (isbn, rating) = (optional("ISBN/UID"), optional("Star Rating"));
+        let (last_read, dates_read, review) = (optional("Last Date Read"), optional("Dates Read"), optional("Review"));
+
+        for record in records {
+            let field = |i: Option<usize>| i.and_then(|i| record.get(i)).map(|v| v.trim()).filter(|v| !v.is_empty());
+            let Some(title) = field(Some(title)) else {
+                continue;
+            };
+            let status = field(Some(status)).and_then(ReadingStatus::from_shelf).unwrap_or_default();
+
+            let mut book = Book::new(title.to_string(), field(Some(authors)).unwrap_or_default().to_string(), status);
+            book.isbn = field(isbn).and_then(clean_isbn);
+            book.rating = field(rating).and_then(|r| r.parse().ok());
+            book.notes = field(review).map(str::to_string);
+
+            // "2024/01/02-2024/01/20, 2025/03/01-" lists every read; the last is current
+            if let Some((start, end)) = field(dates_read).and_then(|dates| dates.rsplit(',').next()?.split_once('-')) {
+                book.started = export_date(start);
+                book.finished = export_date(end);
+            }
+            book.finished = field(last_read).and_then(export_date).or(book.finished);
+            books.push(book);
+        }
+    }
+
+    Ok(books)
+}
+
+/// `2024/03/15` as exported by both services
+fn export_date(value: &str) -> Option<ArticleDate> {
+    ArticleDate::parse(&value.trim().replace('/', "-")).ok()
+}
+
+/// Digits of an ISBN-10 or ISBN-13; Goodreads wraps them as `="0596517742"`
+fn clean_isbn(value: &str) -> Option<String> {
+    let isbn: String = value.chars().filter(|c| c.is_ascii_digit() || *c == 'X').collect();
+    matches!(isbn.len(), 10 | 13).then_some(isbn)
+}
+
+/// Split CSV into records of fields; quoted fields may hold commas, quotes
+/// (`""`) and line breaks
+fn parse_csv(data: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = data.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_goodreads_and_storygraph() {
+        let goodreads = "\u{feff}Book Id,Title,Author,ISBN,ISBN13,My Rating,Date Read,Exclusive Shelf,My Review,Private Notes\r\n\
+            1,\"Release It!, 2nd Edition\",Michael T. Nygard,\"=\"\"1680502395\"\"\",\"=\"\"9781680502398\"\"\",4,2024/03/15,read,\"Great on \"\"stability\"\"\nand timeouts\",secret\r\n\
+            2,Clean Architecture,Robert C. Martin,\"=\"\"\"\"\",\"=\"\"\"\"\",0,,to-read,,\r\n";
+        let books = import_reading_csv(goodreads).unwrap();
+
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].title, "Release It!, 2nd Edition");
+        assert_eq!(books[0].isbn.as_deref(), Some("9781680502398"));
+        assert_eq!(books[0].rating, Some(4.0));
+        assert_eq!(books[0].status, ReadingStatus::Read);
+        assert_eq!(books[0].finished, Some(ArticleDate::parse("2024-03-15").unwrap()));
+        assert_eq!(books[0].notes.as_deref(), Some("Great on \"stability\"\nand timeouts"));
+        assert_eq!(books[1].status, ReadingStatus::WantToRead);
+        assert_eq!((books[1].isbn.as_ref(), books[1].rating), (None, None));
+
+        let storygraph = "Title,Authors,ISBN/UID,Read Status,Last Date Read,Dates Read,Star Rating,Review\n\
+            Designing Data-Intensive Applications,Martin Kleppmann,9781449373320,currently-reading,,\"2023/01/02-2023/02/01, 2025/10/02-\",,\n\
+            Some Novel,A. Writer,uid-123,did-not-finish,,,2.5,\n";
+        let books = import_reading_csv(storygraph).unwrap();
+
+        assert_eq!(books[0].status, ReadingStatus::CurrentlyReading);
+        assert_eq!(books[0].started, Some(ArticleDate::parse("2025-10-02").unwrap()));
+        assert_eq!(books[0].finished, None);
+        assert_eq!(books[1].status, ReadingStatus::DidNotFinish);
+        assert_eq!((books[1].isbn.as_ref(), books[1].rating), (None, Some(2.5)));
+
+        assert_eq!(import_reading_csv("Title,Author\nA,B\n"), Err(ReadingImportError::UnknownFormat));
+    }
+
+    #[test]
+    fn test_merge_keeps_hand_edits() {
+        let mut list = ReadingList::from_toml(
+            r#"
+            [[books]]
+            title = "Release It!"
+            author = "Michael T. Nygard"
+            status = "to-read"
+            review = "Reviews/release-it"
+            notes = "Recommended by a colleague"
+            "#,
+        )
+        .unwrap();
+
+        let mut read = Book::new("Release it".to_string(), "Michael T. Nygard".to_string(), ReadingStatus::Read);
+        read.rating = Some(5.0);
+        read.isbn = Some("9781680502398".to_string());
+        let new = Book::new("Clean Architecture".to_string(), "Robert C. Martin".to_string(), ReadingStatus::Read);
+
+        assert_eq!(list.merge(vec![read, new]), (1, 1));
+        let book = &list.books[0];
+        assert_eq!((book.status, book.rating), (ReadingStatus::Read, Some(5.0)));
+        assert_eq!(book.review.as_deref(), Some("Reviews/release-it"));
+        assert_eq!(book.notes.as_deref(), Some("Recommended by a colleague"));
+        assert_eq!(
+            book.cover_url().as_deref(),
+            Some("https://covers.openlibrary.org/b/isbn/9781680502398-M.jpg")
+        );
+
+        assert_eq!(list.filter(Some(ReadingStatus::Read)).len(), 2);
+        assert_eq!(ReadingList::from_toml(&list.to_toml().unwrap()).unwrap(), list);
+    }
+}
@@ -0,0 +1,612 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rust_stemmers::{Algorithm, Stemmer};
+use serde::{Deserialize, Serialize};
+
+use crate::ArticleDate;
+use crate::excerpt::strip_inline_markdown;
+
+/// How much a term counts depending on where it appears
+const TITLE_WEIGHT: f32 = 5.0;
+const TAG_WEIGHT: f32 = 4.0;
+const HEADING_WEIGHT: f32 = 3.0;
+const SUMMARY_WEIGHT: f32 = 2.0;
+const BODY_WEIGHT: f32 = 1.0;
+
+/// Prefix matches on the word being typed count for less than whole words
+const PREFIX_FACTOR: f32 = 0.5;
+
+/// Approximate snippet length in bytes
+const SNIPPET_LENGTH: usize = 180;
+
+/// Words too common to be worth indexing
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be", "been",
+    "being", "but", "by", "can", "could", "do", "does", "for", "from", "had", "has", "have", "how",
+    "i", "if", "in", "into", "is", "it", "its", "just", "may", "more", "most", "no", "not", "of",
+    "on", "or", "our", "over", "should", "so", "some", "such", "than", "that", "the", "their",
+    "them", "then", "there", "these", "they", "this", "those", "to", "too", "up", "us", "was", "we",
+    "were", "what", "when", "where", "which", "while", "who", "why", "will", "with", "would",
+    "you", "your",
+];
+
+/// An article as handed to [`SearchIndex::build`]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchDocument {
+    /// Article path, returned with results
+    pub id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub tags: Vec<String>,
+    pub series: Vec<String>,
+    pub category: Option<String>,
+    pub lang: String,
+    pub date: Option<ArticleDate>,
+    /// Markdown body; headings are weighted above paragraph text
+    pub body: String,
+}
+
+/// Narrow results down; empty fields match everything
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchFilters {
+    /// Results must carry all of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub series: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+/// A run of snippet text, highlighted when it matched the query
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlight: bool,
+}
+
+/// A ranked search hit
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Article path
+    pub id: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub series: Vec<String>,
+    pub date: Option<ArticleDate>,
+    pub score: f32,
+    pub snippet: Vec<SnippetPart>,
+}
+
+/// What the index keeps about each document
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct StoredDocument {
+    id: String,
+    title: String,
+    summary: Option<String>,
+    tags: Vec<String>,
+    series: Vec<String>,
+    category: Option<String>,
+    lang: String,
+    date: Option<ArticleDate>,
+    /// Plain body text used for snippets
+    text: String,
+}
+
+impl StoredDocument {
+    /// Fold every field into `hash`, each length-prefixed so text can't
+    /// move between neighbouring fields without changing the result
+    fn hash_into(&self, hash: u64, body: &str) -> u64 {
+        let date = self.date.as_ref().map(ArticleDate::to_rfc3339);
+        let mut hash = hash;
+        for field in [&self.id, &self.title, &self.lang, &self.text, body] {
+            hash = hash_field(hash, Some(field));
+        }
+        for field in [&self.summary, &self.category, &date] {
+            hash = hash_field(hash, field.as_deref());
+        }
+        for list in [&self.tags, &self.series] {
+            hash = fnv1a(hash, &(list.len() as u64).to_le_bytes());
+            for item in list {
+                hash = hash_field(hash, Some(item));
+            }
+        }
+        hash
+    }
+}
+
+/// Inverted index over titles, tags, headings, summaries and body text
+///
+/// Terms are lower-cased, stop-words dropped and the rest stemmed, so
+/// "running" finds "runs". The index serializes, so it can be shipped to
+/// and queried in the browser.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// Changes whenever the indexed content changes
+    pub version: u64,
+    documents: Vec<StoredDocument>,
+    /// Stemmed term -> (document, weighted term frequency)
+    postings: BTreeMap<String, Vec<(u32, f32)>>,
+}
+
+/// Split text into words, with their byte offsets
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(move |word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+}
+
+struct Analyzer {
+    stemmer: Stemmer,
+}
+
+impl Analyzer {
+    fn new() -> Self {
+        Analyzer {
+            stemmer: Stemmer::create(Algorithm::English),
+        }
+    }
+
+    /// The indexed form of a word, or `None` for stop-words and single letters
+    fn term(&self, word: &str) -> Option<String> {
+        let word = word.to_lowercase();
+        if word.chars().count() < 2 || STOP_WORDS.contains(&word.as_str()) {
+            return None;
+        }
+        Some(self.stemmer.stem(&word).into_owned())
+    }
+
+    fn add_terms(&self, scores: &mut BTreeMap<String, f32>, text: &str, weight: f32) {
+        for (_, word) in words(text) {
+            if let Some(term) = self.term(word) {
+                *scores.entry(term).or_default() += weight;
+            }
+        }
+    }
+}
+
+/// Split markdown into headings and plain paragraph text, skipping code blocks
+fn markdown_text(markdown: &str) -> (Vec<String>, String) {
+    let mut headings = Vec::new();
+    let mut text = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let line = line.trim();
+
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || line.is_empty() || line.starts_with('!') || line.starts_with('<') {
+            continue;
+        }
+
+        if line.starts_with('#') {
+            headings.push(strip_inline_markdown(line.trim_start_matches('#').trim()));
+            continue;
+        }
+
+        let line = line.trim_start_matches(['>', '-', '*', '+', '|']).trim_start();
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&strip_inline_markdown(line));
+    }
+
+    (headings, text)
+}
+
+/// FNV-1a, stable across platforms and builds
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Add one field to an FNV-1a hash: a presence byte, then its length, then its bytes
+fn hash_field(hash: u64, field: Option<&str>) -> u64 {
+    match field {
+        None => fnv1a(hash, &[0]),
+        Some(field) => {
+            let hash = fnv1a(hash, &[1]);
+            let hash = fnv1a(hash, &(field.len() as u64).to_le_bytes());
+            fnv1a(hash, field.as_bytes())
+        }
+    }
+}
+
+impl SearchIndex {
+    /// Index `documents`
+    pub fn build(documents: impl IntoIterator<Item = SearchDocument>) -> Self {
+        let analyzer = Analyzer::new();
+        let mut index = SearchIndex {
+            version: 0xcbf29ce484222325,
+            ..Default::default()
+        };
+
+        for document in documents {
+            let position = index.documents.len() as u32;
+            let (headings, text) = markdown_text(&document.body);
+
+            let mut scores = BTreeMap::new();
+            analyzer.add_terms(&mut scores, &document.title, TITLE_WEIGHT);
+            for tag in &document.tags {
+                analyzer.add_terms(&mut scores, tag, TAG_WEIGHT);
+            }
+            for heading in &headings {
+                analyzer.add_terms(&mut scores, heading, HEADING_WEIGHT);
+            }
+            if let Some(ref summary) = document.summary {
+                analyzer.add_terms(&mut scores, summary, SUMMARY_WEIGHT);
+            }
+            analyzer.add_terms(&mut scores, &text, BODY_WEIGHT);
+
+            for (term, score) in scores {
+                index.postings.entry(term).or_default().push((position, score));
+            }
+
+            let stored = StoredDocument {
+                id: document.id,
+                title: document.title,
+                summary: document.summary,
+                tags: document.tags,
+                series: document.series,
+                category: document.category,
+                lang: document.lang,
+                date: document.date,
+                text,
+            };
+            index.version = stored.hash_into(index.version, &document.body);
+            index.documents.push(stored);
+        }
+
+        index
+    }
+
+    /// Number of indexed documents
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// A smaller copy for shipping to the browser
+    ///
+    /// Postings and the version are kept, so ranking is unchanged, but each
+    /// document's snippet text is cut to about `max_text_len` bytes.
+    /// Matches past the cut fall back to the summary or opening text for their snippet.
+    pub fn compact(&self, max_text_len: usize) -> SearchIndex {
+        let mut compact = self.clone();
+        for document in &mut compact.documents {
+            if document.text.len() > max_text_len {
+                let mut end = max_text_len;
+                while !document.text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                // Don't leave half a word behind
+                if document.text[end..].starts_with(char::is_alphanumeric) {
+                    end = document.text[..end].rfind(' ').unwrap_or(end);
+                }
+                document.text.truncate(end);
+            }
+        }
+        compact
+    }
+
+    /// Ranked results for `query`, best first
+    ///
+    /// The last word also matches as a prefix while it is being typed
+    /// (the query doesn't end in a space).
+    pub fn search(&self, query: &str, filters: &SearchFilters, limit: usize) -> Vec<SearchResult> {
+        let analyzer = Analyzer::new();
+
+        let terms: BTreeSet<String> = words(query).filter_map(|(_, word)| analyzer.term(word)).collect();
+        let prefix = words(query)
+            .last()
+            .filter(|_| !query.ends_with(char::is_whitespace))
+            .map(|(_, word)| word.to_lowercase())
+            .filter(|word| word.chars().count() >= 2);
+
+        if terms.is_empty() && prefix.is_none() {
+            return Vec::new();
+        }
+
+        let total = self.documents.len() as f32;
+        let mut scores: BTreeMap<u32, f32> = BTreeMap::new();
+        let mut add_postings = |postings: &[(u32, f32)], factor: f32| {
+            let idf = (1.0 + total / postings.len() as f32).ln();
+            for (document, weight) in postings {
+                *scores.entry(*document).or_default() += (1.0 + weight.ln()) * idf * factor;
+            }
+        };
+
+        for term in &terms {
+            if let Some(postings) = self.postings.get(term) {
+                add_postings(postings, 1.0);
+            }
+        }
+        if let Some(ref prefix) = prefix {
+            let completions = self
+                .postings
+                .range(prefix.clone()..)
+                .take_while(|(term, _)| term.starts_with(prefix.as_str()))
+                .filter(|(term, _)| !terms.contains(*term));
+            for (_, postings) in completions {
+                add_postings(postings, PREFIX_FACTOR);
+            }
+        }
+
+        let mut results: Vec<SearchResult> = scores
+            .into_iter()
+            .filter_map(|(position, score)| {
+                let document = &self.documents[position as usize];
+                if !matches_filters(document, filters) {
+                    return None;
+                }
+
+                let snippet = snippet(&document.text, &analyzer, &terms, prefix.as_deref())
+                    .or_else(|| {
+                        let summary = document.summary.as_deref()?;
+                        snippet(summary, &analyzer, &terms, prefix.as_deref())
+                    })
+                    .unwrap_or_else(|| {
+                        let text = document.summary.as_deref().unwrap_or(&document.text);
+                        leading_snippet(text)
+                    });
+
+                Some(SearchResult {
+                    id: document.id.clone(),
+                    title: document.title.clone(),
+                    tags: document.tags.clone(),
+                    series: document.series.clone(),
+                    date: document.date,
+                    score,
+                    snippet,
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        results.truncate(limit);
+        results
+    }
+}
+
+fn matches_filters(document: &StoredDocument, filters: &SearchFilters) -> bool {
+    let has_tags = filters.tags.iter().all(|tag| document.tags.contains(tag));
+    let in_series = filters.series.as_ref().is_none_or(|series| document.series.contains(series));
+    let in_category = filters
+        .category
+        .as_ref()
+        .is_none_or(|category| document.category.as_ref() == Some(category));
+    let in_lang = filters.lang.as_ref().is_none_or(|lang| document.lang == *lang);
+
+    has_tags && in_series && in_category && in_lang
+}
+
+/// A window of `text` around the first matching word, with matches highlighted
+fn snippet(
+    text: &str,
+    analyzer: &Analyzer,
+    terms: &BTreeSet<String>,
+    prefix: Option<&str>,
+) -> Option<Vec<SnippetPart>> {
+    let is_match = |word: &str| {
+        analyzer.term(word).is_some_and(|term| terms.contains(&term))
+            || prefix.is_some_and(|prefix| word.to_lowercase().starts_with(prefix))
+    };
+
+    let all_words: Vec<(usize, &str)> = words(text).collect();
+    let first = all_words.iter().position(|(_, word)| is_match(word))?;
+
+    // Start a few words before the first match
+    let first_in_window = first.saturating_sub(8);
+    let start = all_words[first_in_window].0;
+    let window = all_words[first_in_window..]
+        .iter()
+        .take_while(|(offset, word)| offset + word.len() - start <= SNIPPET_LENGTH)
+        .count();
+    let end = if first_in_window + window == all_words.len() {
+        text.len()
+    } else {
+        let (offset, word) = all_words[(first_in_window + window).max(first + 1) - 1];
+        offset + word.len()
+    };
+
+    let mut parts = Vec::new();
+    if start > 0 {
+        push_part(&mut parts, "…", false);
+    }
+
+    let mut cursor = start;
+    for (offset, word) in all_words.iter().filter(|(offset, _)| *offset >= start && *offset < end) {
+        push_part(&mut parts, &text[cursor..*offset], false);
+        push_part(&mut parts, word, is_match(word));
+        cursor = offset + word.len();
+    }
+    push_part(&mut parts, &text[cursor..end], false);
+
+    if end < text.len() {
+        push_part(&mut parts, "…", false);
+    }
+
+    Some(parts)
+}
+
+/// The opening of `text`, without highlights
+fn leading_snippet(text: &str) -> Vec<SnippetPart> {
+    let ends: Vec<usize> = words(text).map(|(offset, word)| offset + word.len()).collect();
+    let fitting = ends.iter().take_while(|end| **end <= SNIPPET_LENGTH).count();
+    let end = match fitting {
+        count if count == ends.len() => text.len(),
+        0 => 0,
+        count => ends[count - 1],
+    };
+
+    let mut parts = Vec::new();
+    push_part(&mut parts, &text[..end], false);
+    if end < text.len() {
+        push_part(&mut parts, "…", false);
+    }
+    parts
+}
+
+/// Append text, merging it into the previous part when the highlighting matches
+fn push_part(parts: &mut Vec<SnippetPart>, text: &str, highlight: bool) {
+    if text.is_empty() {
+        return;
+    }
+    match parts.last_mut() {
+        Some(last) if last.highlight == highlight => last.text.push_str(text),
+        _ => parts.push(SnippetPart {
+            text: text.to_string(),
+            highlight,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn documents() -> Vec<SearchDocument> {
+        vec![
+            SearchDocument {
+                id: "rust/01-intro.md".to_string(),
+                title: "Getting Started with Rust".to_string(),
+                tags: vec!["rust".to_string()],
+                series: vec!["rust".to_string()],
+                lang: "en".to_string(),
+                body: "# Installing\n\nRun rustup to install the toolchain.\n\n```sh\ncurl netabase\n```"
+                    .to_string(),
+                ..Default::default()
+            },
+            SearchDocument {
+                id: "netabase.md".to_string(),
+                title: "Building netabase_store".to_string(),
+                summary: Some("A storage layer that runs anywhere".to_string()),
+                tags: vec!["database".to_string(), "rust".to_string()],
+                lang: "en".to_string(),
+                body: "The store is running on IndexedDB in the browser and sled on native targets."
+                    .to_string(),
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn ids(results: &[SearchResult]) -> Vec<&str> {
+        results.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_ranks_and_stems() {
+        let index = SearchIndex::build(documents());
+
+        // Title and tag matches outrank body matches
+        let results = index.search("rust ", &SearchFilters::default(), 10);
+        assert_eq!(ids(&results), ["rust/01-intro.md", "netabase.md"]);
+
+        // "running" and "runs" share a stem with "run"
+        let results = index.search("running ", &SearchFilters::default(), 10);
+        assert_eq!(results.len(), 2);
+
+        // Stop-words alone match nothing, and code blocks aren't indexed
+        assert!(index.search("the and ", &SearchFilters::default(), 10).is_empty());
+        assert!(index.search("curl ", &SearchFilters::default(), 10).is_empty());
+    }
+
+    #[test]
+    fn test_search_prefix_and_filters() {
+        let index = SearchIndex::build(documents());
+
+        assert_eq!(ids(&index.search("netab", &SearchFilters::default(), 10)), ["netabase.md"]);
+
+        let filters = SearchFilters {
+            tags: vec!["database".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(ids(&index.search("rust ", &filters, 10)), ["netabase.md"]);
+
+        let filters = SearchFilters {
+            series: Some("rust".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&index.search("rust ", &filters, 10)), ["rust/01-intro.md"]);
+    }
+
+    #[test]
+    fn test_snippet_highlights_matches() {
+        let index = SearchIndex::build(documents());
+        let results = index.search("indexeddb ", &SearchFilters::default(), 10);
+
+        let highlighted: Vec<&str> = results[0]
+            .snippet
+            .iter()
+            .filter(|part| part.highlight)
+            .map(|part| part.text.as_str())
+            .collect();
+        assert_eq!(highlighted, ["IndexedDB"]);
+
+        let text: String = results[0].snippet.iter().map(|part| part.text.as_str()).collect();
+        assert_eq!(text, "The store is running on IndexedDB in the browser and sled on native targets.");
+    }
+
+    #[test]
+    fn test_compact_keeps_ranking() {
+        let index = SearchIndex::build(documents());
+        let compact = index.compact(20);
+
+        assert_eq!(compact.version, index.version);
+        assert_eq!(
+            ids(&compact.search("rust ", &SearchFilters::default(), 10)),
+            ids(&index.search("rust ", &SearchFilters::default(), 10))
+        );
+
+        // A match past the cut still ranks, with the summary as its snippet
+        let results = compact.search("sled ", &SearchFilters::default(), 10);
+        assert_eq!(ids(&results), ["netabase.md"]);
+        let text: String = results[0].snippet.iter().map(|part| part.text.as_str()).collect();
+        assert_eq!(text, "A storage layer that runs anywhere");
+    }
+
+    #[test]
+    fn test_version_tracks_content() {
+        let mut changed = documents();
+        changed[1].body.push_str(" Now with transactions.");
+
+        assert_eq!(SearchIndex::build(documents()).version, SearchIndex::build(documents()).version);
+        assert_ne!(SearchIndex::build(documents()).version, SearchIndex::build(changed).version);
+    }
+
+    #[test]
+    fn test_version_tracks_every_field() {
+        let version = SearchIndex::build(documents()).version;
+
+        let mut summary = documents();
+        summary[1].summary = Some("A storage layer that runs everywhere".to_string());
+        assert_ne!(SearchIndex::build(summary).version, version);
+
+        let mut no_summary = documents();
+        no_summary[1].summary = None;
+        assert_ne!(SearchIndex::build(no_summary).version, version);
+
+        let mut date = documents();
+        date[0].date = Some(ArticleDate::parse("2025-11-20").unwrap());
+        assert_ne!(SearchIndex::build(date).version, version);
+
+        // Text moving from one field to the next is still a change
+        let mut shifted = documents();
+        shifted[0].id = "rust/01-intro.mdGetting".to_string();
+        shifted[0].title = " Started with Rust".to_string();
+        assert_ne!(SearchIndex::build(shifted).version, version);
+
+        let mut tags = documents();
+        tags[1].tags = vec!["databaserust".to_string()];
+        assert_ne!(SearchIndex::build(tags).version, version);
+    }
+}
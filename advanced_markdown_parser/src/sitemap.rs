@@ -0,0 +1,127 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::feed::escape_xml;
+use crate::ArticleDate;
+
+/// One page listed in `sitemap.xml`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SitemapEntry {
+    /// Absolute URL of the page
+    pub loc: String,
+    /// When the page's content last changed, if known
+    #[serde(default)]
+    pub lastmod: Option<ArticleDate>,
+}
+
+/// Render a sitemap (sitemaps.org protocol 0.9)
+///
+/// Entries keep their order; duplicate URLs after the first are dropped.
+pub fn render_sitemap(entries: &[SitemapEntry]) -> String {
+    let mut seen = std::collections::BTreeSet::new();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+
+    for entry in entries.iter().filter(|entry| seen.insert(entry.loc.as_str())) {
+        xml.push_str("<url>");
+        let _ = write!(xml, "<loc>{}</loc>", escape_xml(&entry.loc));
+        if let Some(lastmod) = entry.lastmod {
+            let _ = write!(xml, "<lastmod>{}</lastmod>", lastmod.to_rfc3339());
+        }
+        xml.push_str("</url>\n");
+    }
+
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// `robots.txt` that points crawlers at the sitemap
+///
+/// `rules` is the site's own file, if any; without one every path is
+/// allowed. A `Sitemap:` line is appended unless the rules already have one.
+pub fn render_robots(rules: Option<&str>, sitemap_url: &str) -> String {
+    let mut robots = rules
+        .map(|rules| rules.trim_end().to_string())
+        .unwrap_or_else(|| "User-agent: *\nAllow: /".to_string());
+
+    let has_sitemap = robots
+        .lines()
+        .any(|line| line.trim_start().to_ascii_lowercase().starts_with("sitemap:"));
+    if !has_sitemap {
+        let _ = write!(robots, "\n\nSitemap: {}", sitemap_url);
+    }
+
+    robots.push('\n');
+    robots
+}
+
+/// Percent-encode a single URL path segment or query value
+///
+/// Everything but unreserved characters is encoded, so `/`, `?`, `&` and
+/// spaces in names can't change the URL's structure.
+pub fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_segment() {
+        assert_eq!(encode_segment("rust"), "rust");
+        assert_eq!(encode_segment("Project Management"), "Project%20Management");
+        assert_eq!(encode_segment("a/b?c&d=e#f"), "a%2Fb%3Fc%26d%3De%23f");
+        assert_eq!(encode_segment("café"), "caf%C3%A9");
+    }
+
+    #[test]
+    fn test_render_sitemap() {
+        let entries = [
+            SitemapEntry {
+                loc: "https://example.com/".to_string(),
+                lastmod: None,
+            },
+            SitemapEntry {
+                loc: "https://example.com/tags/a&b".to_string(),
+                lastmod: Some(ArticleDate::parse("2025-11-20").unwrap()),
+            },
+            SitemapEntry {
+                loc: "https://example.com/".to_string(),
+                lastmod: None,
+            },
+        ];
+
+        let xml = render_sitemap(&entries);
+
+        assert_eq!(xml.matches("<url>").count(), 2);
+        assert!(xml.contains("<url><loc>https://example.com/</loc></url>"));
+        assert!(xml.contains(
+            "<url><loc>https://example.com/tags/a&amp;b</loc><lastmod>2025-11-20T00:00:00Z</lastmod></url>"
+        ));
+    }
+
+    #[test]
+    fn test_render_robots() {
+        let sitemap = "https://example.com/sitemap.xml";
+
+        assert_eq!(
+            render_robots(None, sitemap),
+            "User-agent: *\nAllow: /\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+        assert_eq!(
+            render_robots(Some("User-agent: *\nDisallow: /saved\n"), sitemap),
+            "User-agent: *\nDisallow: /saved\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+
+        let custom = "User-agent: *\nSitemap: https://cdn.example.com/sitemap.xml\n";
+        assert_eq!(render_robots(Some(custom), sitemap), custom);
+    }
+}
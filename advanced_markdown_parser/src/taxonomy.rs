@@ -0,0 +1,308 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ArticleTomlMetadata, DiagnosticSeverity, MetadataDiagnostic};
+
+/// A canonical tag from the taxonomy file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TagDefinition {
+    /// Other spellings that mean this tag ("Rust", "rust-lang", ...)
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Category for articles carrying this tag that don't set one
+    #[serde(default)]
+    pub category: Option<String>,
+}
+
+/// A canonical category from the taxonomy file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CategoryDefinition {
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Site-level vocabulary for tags, topics and categories
+///
+/// ```toml
+/// [tags.rust]
+/// aliases = ["rust-lang"]
+/// description = "The Rust programming language"
+/// category = "Programming"
+///
+/// [categories.Programming]
+/// description = "Languages, tools and techniques"
+/// ```
+///
+/// Tags and topics share the tag vocabulary. Values are matched ignoring
+/// case, spaces, underscores and hyphens, so "Rust", "rust" and "rust-lang"
+/// all become `rust`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Taxonomy {
+    #[serde(default)]
+    pub tags: BTreeMap<String, TagDefinition>,
+    #[serde(default)]
+    pub categories: BTreeMap<String, CategoryDefinition>,
+}
+
+/// Lookup form of a term: lower-case with separators removed
+fn term_key(term: &str) -> String {
+    term.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Edit distance between two terms, for spotting typos
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Whether two different lookup keys are probably the same term misspelt
+fn is_near_duplicate(a: &str, b: &str) -> bool {
+    let shorter = a.chars().count().min(b.chars().count());
+    let allowed = match shorter {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    };
+    a != b && edit_distance(a, b) <= allowed
+}
+
+impl Taxonomy {
+    /// Parse a taxonomy file
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// The canonical tag for `value`, by name or alias
+    pub fn canonical_tag(&self, value: &str) -> Option<&str> {
+        let key = term_key(value);
+        self.tags
+            .iter()
+            .find(|(name, definition)| {
+                term_key(name) == key || definition.aliases.iter().any(|alias| term_key(alias) == key)
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// The canonical category for `value`, by name or alias
+    pub fn canonical_category(&self, value: &str) -> Option<&str> {
+        let key = term_key(value);
+        self.categories
+            .iter()
+            .find(|(name, definition)| {
+                term_key(name) == key || definition.aliases.iter().any(|alias| term_key(alias) == key)
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// A canonical tag that `value` looks like a misspelling of
+    fn similar_tag(&self, value: &str) -> Option<&str> {
+        let key = term_key(value);
+        self.tags
+            .iter()
+            .find(|(name, definition)| {
+                std::iter::once(*name)
+                    .chain(&definition.aliases)
+                    .any(|known| is_near_duplicate(&key, &term_key(known)))
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Rewrite tags, topics and category to their canonical names
+    ///
+    /// Duplicates left after rewriting are dropped. Articles without a
+    /// category get the parent category of their first tag that has one.
+    /// Unknown values are kept and reported, with a suggestion when they
+    /// look like a misspelt canonical tag. Nothing is reported while the
+    /// taxonomy defines no tags.
+    pub fn normalize(&self, metadata: &mut ArticleTomlMetadata) -> Vec<MetadataDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        metadata.tags = self.normalize_terms(&metadata.tags, "tags", &mut diagnostics);
+        metadata.topics = self.normalize_terms(&metadata.topics, "topics", &mut diagnostics);
+
+        match metadata.category.take() {
+            Some(category) => {
+                let canonical = self.canonical_category(&category).map(str::to_string);
+                if canonical.is_none() && !self.categories.is_empty() {
+                    diagnostics.push(warning("category", format!("unknown category \"{}\"", category)));
+                }
+                metadata.category = Some(canonical.unwrap_or(category));
+            }
+            None => {
+                metadata.category = metadata
+                    .tags
+                    .iter()
+                    .filter_map(|tag| self.tags.get(tag))
+                    .find_map(|definition| definition.category.clone());
+            }
+        }
+
+        diagnostics
+    }
+
+    fn normalize_terms(
+        &self,
+        values: &[String],
+        field: &str,
+        diagnostics: &mut Vec<MetadataDiagnostic>,
+    ) -> Vec<String> {
+        let mut seen = BTreeSet::new();
+        let mut terms = Vec::new();
+
+        for value in values {
+            let term = match self.canonical_tag(value) {
+                Some(canonical) => canonical.to_string(),
+                None => {
+                    if !self.tags.is_empty() {
+                        let message = match self.similar_tag(value) {
+                            Some(similar) => format!("unknown tag \"{}\" (did you mean \"{}\"?)", value, similar),
+                            None => format!("unknown tag \"{}\"", value),
+                        };
+                        diagnostics.push(warning(field, message));
+                    }
+                    value.clone()
+                }
+            };
+
+            if seen.insert(term.clone()) {
+                terms.push(term);
+            }
+        }
+
+        terms
+    }
+}
+
+fn warning(field: &str, message: String) -> MetadataDiagnostic {
+    MetadataDiagnostic {
+        severity: DiagnosticSeverity::Warning,
+        field: Some(field.to_string()),
+        message,
+    }
+}
+
+/// Pairs of terms in use that are probably the same term spelt differently
+///
+/// Catches variants the taxonomy doesn't know about yet, such as
+/// "database" and "databse" or "Rust" and "rust".
+pub fn near_duplicate_terms<'a>(terms: impl IntoIterator<Item = &'a str>) -> Vec<(String, String)> {
+    let terms: BTreeSet<&str> = terms.into_iter().collect();
+    let keyed: Vec<(&str, String)> = terms.iter().map(|term| (*term, term_key(term))).collect();
+
+    let mut pairs = Vec::new();
+    for (i, (a, key_a)) in keyed.iter().enumerate() {
+        for (b, key_b) in &keyed[i + 1..] {
+            if key_a == key_b || is_near_duplicate(key_a, key_b) {
+                pairs.push((a.to_string(), b.to_string()));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxonomy() -> Taxonomy {
+        Taxonomy::from_toml(
+            r#"
+            [tags.rust]
+            aliases = ["rust-lang"]
+            category = "Programming"
+
+            [tags.database]
+            description = "Storage engines"
+
+            [categories.Programming]
+            aliases = ["programming-languages"]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_normalize_canonicalizes_and_dedups() {
+        let mut metadata = ArticleTomlMetadata {
+            tags: vec!["Rust".to_string(), "rust-lang".to_string(), "Data Base".to_string()],
+            topics: vec!["RUST".to_string()],
+            ..Default::default()
+        };
+
+        let diagnostics = taxonomy().normalize(&mut metadata);
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(metadata.tags, ["rust", "database"]);
+        assert_eq!(metadata.topics, ["rust"]);
+        // Inherited from the first tag with a parent category
+        assert_eq!(metadata.category.as_deref(), Some("Programming"));
+
+        let mut metadata = ArticleTomlMetadata {
+            category: Some("programming languages".to_string()),
+            ..Default::default()
+        };
+        taxonomy().normalize(&mut metadata);
+        assert_eq!(metadata.category.as_deref(), Some("Programming"));
+    }
+
+    #[test]
+    fn test_normalize_warns_about_unknown_tags() {
+        let mut metadata = ArticleTomlMetadata {
+            tags: vec!["databse".to_string(), "wasm".to_string()],
+            category: Some("Cooking".to_string()),
+            ..Default::default()
+        };
+
+        let messages: Vec<String> = taxonomy()
+            .normalize(&mut metadata)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            messages,
+            [
+                "tags: unknown tag \"databse\" (did you mean \"database\"?)",
+                "tags: unknown tag \"wasm\"",
+                "category: unknown category \"Cooking\"",
+            ]
+        );
+        // Unknown values are kept
+        assert_eq!(metadata.tags, ["databse", "wasm"]);
+
+        // An empty taxonomy normalizes nothing and stays quiet
+        assert!(Taxonomy::default().normalize(&mut metadata).is_empty());
+    }
+
+    #[test]
+    fn test_near_duplicate_terms() {
+        let pairs = near_duplicate_terms(["Rust", "rust", "database", "databse", "go", "js"]);
+
+        assert_eq!(
+            pairs,
+            [
+                ("Rust".to_string(), "rust".to_string()),
+                ("database".to_string(), "databse".to_string()),
+            ]
+        );
+    }
+}
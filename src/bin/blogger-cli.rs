@@ -0,0 +1,5 @@
+//! Authoring tool for the blog's content; see `blogger::cli`
+
+fn main() {
+    std::process::exit(blogger::cli::run(std::env::args().skip(1).collect()));
+}
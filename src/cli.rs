@@ -0,0 +1,541 @@
+//! `blogger-cli`: authoring commands that share the server's content logic
+//!
+//! ```text
+//! blogger-cli new article <title> [--series <name>] [--slug <slug>]
+//! blogger-cli new series <name> [--summary <text>]
+//! blogger-cli lint [--json] [--strict]
+//! blogger-cli reading import <csv>
+//! blogger-cli graph
+//! blogger-cli index [--json]
+//! blogger-cli export [dir] [--server <path>]
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Component, Path, PathBuf};
+
+use advanced_markdown_parser::{import_reading_csv, ReadingList};
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use crate::markdown_management::authors::load_author_registry;
+use crate::markdown_management::index::content_index;
+use crate::markdown_management::lint::lint_content_tree;
+use crate::markdown_management::local::{collect_markdown_files_sync, relative_article_path};
+use crate::markdown_management::site::{articles_dir, content_paths, init_site_config};
+use crate::markdown_management::build_content_index_blocking;
+
+const USAGE: &str = "\
+Usage: blogger-cli <command>
+
+Commands:
+  new article <title> [--series <name>] [--slug <slug>]   Create an article with front matter filled in
+  new series <name> [--summary <text>]                    Create a series folder with a summary.md
+  lint [--json] [--strict]                                Check the content tree; --strict fails on warnings
+  reading import <csv>                                    Merge a Goodreads or StoryGraph export into reading.toml
+  graph                                                   Print series and prev/next links as Graphviz DOT
+  index [--json]                                          Build the content index and summarise it
+  export [dir] [--server <path>]                          Pre-render the site into a static directory";
+
+/// Arguments after the command words: positionals and `--flag value` pairs
+struct Args {
+    positional: Vec<String>,
+    flags: BTreeMap<String, String>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: &[String], switches: &[&str]) -> Result<Self> {
+        let mut parsed = Args {
+            positional: Vec::new(),
+            flags: BTreeMap::new(),
+            switches: Vec::new(),
+        };
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if switches.contains(&name) => parsed.switches.push(name.to_string()),
+                Some(name) => {
+                    let value = iter.next().with_context(|| format!("--{} needs a value", name))?;
+                    parsed.flags.insert(name.to_string(), value.clone());
+                }
+                None => parsed.positional.push(arg.clone()),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn flag(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).map(String::as_str)
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+}
+
+/// Run the CLI with `args` (program name excluded) and return the exit code
+pub fn run(args: Vec<String>) -> i32 {
+    match init_site_config().map_err(anyhow::Error::from).and_then(|_| dispatch(args)) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            2
+        }
+    }
+}
+
+/// Commands that read the registries run on a temporary runtime; the
+/// index-building ones start their own
+fn block_on<T>(future: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(future)
+}
+
+fn dispatch(args: Vec<String>) -> Result<i32> {
+    let words: Vec<&str> = args.iter().take(2).map(String::as_str).collect();
+    match words.as_slice() {
+        ["new", "article", ..] => block_on(new_article(Args::parse(&args[2..], &[])?)),
+        ["new", "series", ..] => new_series(Args::parse(&args[2..], &[])?),
+        ["lint", ..] => block_on(lint(Args::parse(&args[1..], &["json", "strict"])?)),
+        ["reading", "import", ..] => reading_import(Args::parse(&args[2..], &[])?),
+        ["graph", ..] => graph(),
+        ["index", ..] => index(Args::parse(&args[1..], &["json"])?),
+        ["export", ..] => export(Args::parse(&args[1..], &[])?),
+        ["help", ..] | ["--help", ..] | ["-h", ..] => {
+            println!("{}", USAGE);
+            Ok(0)
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            Ok(2)
+        }
+    }
+}
+
+/// Front matter of an article created by `new article`
+#[derive(Serialize)]
+struct NewArticle<'a> {
+    /// Stable identity for feed GUIDs, so renaming the file later is safe
+    id: String,
+    date: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<&'a str>,
+    summary: &'a str,
+    tags: Vec<String>,
+    draft: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    article_series: Vec<NewSeriesEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct NewSeriesEntry<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prev: Option<String>,
+}
+
+/// Front matter of a series `summary.md`
+#[derive(Serialize)]
+struct NewSeries<'a> {
+    short_summary: &'a str,
+    name: &'a str,
+}
+
+/// URL-friendly slug: lower-case words joined by hyphens
+fn slugify(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// A `--series`, `--slug` or series name as a path under `articles/`,
+/// refusing absolute paths and `..` the way [`ArticleId`] refuses them in requests
+///
+/// [`ArticleId`]: advanced_markdown_parser::ArticleId
+fn content_path<'a>(value: &'a str, what: &str) -> Result<&'a Path> {
+    let path = Path::new(value);
+    let relative = path.components().all(|component| matches!(component, Component::Normal(_)));
+    if value.is_empty() || !relative {
+        bail!("{} {:?} must be a relative path without `..`", what, value);
+    }
+    Ok(path)
+}
+
+/// Leading part number of a series file (`03-metrics.md` -> 3)
+fn part_number(path: &Path) -> Option<u32> {
+    let stem = path.file_stem()?.to_str()?;
+    let (number, _) = stem.split_once('-')?;
+    number.parse().ok()
+}
+
+async fn new_article(args: Args) -> Result<i32> {
+    let Some(title) = args.positional.first() else {
+        bail!("new article needs a title");
+    };
+    let slug = args.flag("slug").map(str::to_string).unwrap_or_else(|| slugify(title));
+    if slug.is_empty() {
+        bail!("could not derive a slug from {:?}; pass --slug", title);
+    }
+    content_path(&slug, "slug")?;
+
+    let registry = load_author_registry().await.map_err(anyhow::Error::msg)?;
+    let date = chrono::Local::now().format("%Y-%m-%d");
+
+    let mut front_matter = NewArticle {
+        id: slug.clone(),
+        date: date.to_string(),
+        authors: registry.owner.as_deref().into_iter().collect(),
+        summary: "",
+        tags: Vec::new(),
+        draft: true,
+        article_series: Vec::new(),
+    };
+
+    let (path, previous) = match args.flag("series") {
+        Some(series) => {
+            let dir = Path::new(&articles_dir()).join(content_path(series, "series")?);
+            if !dir.is_dir() {
+                bail!("no series folder at {}; create it with `new series {}`", dir.display(), series);
+            }
+
+            // The last numbered part, ignoring translations (`01-intro.fr.md`)
+            let last = collect_markdown_files_sync(&dir)?
+                .into_iter()
+                .map(|file| file.path)
+                .filter(|path| path.file_stem().and_then(|s| s.to_str()).is_some_and(|s| !s.contains('.')))
+                .filter_map(|path| Some((part_number(&path)?, path)))
+                .max();
+            let number = last.as_ref().map(|(n, _)| n + 1).unwrap_or(1);
+            let path = dir.join(format!("{:02}-{}.md", number, slug));
+
+            let previous = last.map(|(_, path)| path);
+            // Without the part number, so reordering parts keeps the id
+            front_matter.id = format!("{}/{}", series, slug);
+            front_matter.article_series.push(NewSeriesEntry {
+                name: series,
+                prev: previous.as_deref().map(series_link),
+            });
+            (path, previous)
+        }
+        None => (Path::new(&articles_dir()).join(format!("{}.md", slug)), None),
+    };
+
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+    let front_matter = toml::to_string(&front_matter)?;
+    std::fs::write(&path, format!("#####\n{}#####\n# {}\n\n", front_matter, title))?;
+    println!("Created {}", path.display());
+
+    if let (Some(previous), Some(series)) = (previous, args.flag("series")) {
+        if link_next(&previous, series, &series_link(&path))? {
+            println!("Linked {} to it", previous.display());
+        } else {
+            println!("Left {} unchanged; it already has a next part", previous.display());
+        }
+    }
+    Ok(0)
+}
+
+/// Path used in `prev`/`next` links: relative to `articles/`, without `.md`
+fn series_link(path: &Path) -> String {
+    relative_article_path(path, &articles_dir())
+        .trim_start_matches('/')
+        .trim_end_matches(".md")
+        .to_string()
+}
+
+/// Add `next = "<link>"` to the `[[article_series]]` entry for `series` in
+/// `path`'s front matter, unless it already has one
+fn link_next(path: &Path, series: &str, link: &str) -> Result<bool> {
+    let content = std::fs::read_to_string(path)?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let is_entry = |line: &String| {
+        front_matter_line(line).is_some_and(|table| table.get("name").and_then(toml::Value::as_str) == Some(series))
+    };
+    let Some(start) = lines.iter().position(is_entry) else {
+        return Ok(false);
+    };
+    // The entry runs until the next table or the end of the front matter
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('[') || line.trim() == "#####")
+        .map(|offset| start + 1 + offset)
+        .unwrap_or(lines.len());
+    if lines[start..end]
+        .iter()
+        .any(|line| front_matter_line(line).is_some_and(|table| table.contains_key("next")))
+    {
+        return Ok(false);
+    }
+
+    lines.insert(end, format!("next = {}", toml::Value::String(link.to_string())));
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    std::fs::write(path, updated)?;
+    Ok(true)
+}
+
+/// A single `key = value` line of front matter, parsed
+fn front_matter_line(line: &str) -> Option<toml::Table> {
+    toml::from_str(line.trim()).ok()
+}
+
+fn new_series(args: Args) -> Result<i32> {
+    let Some(name) = args.positional.first() else {
+        bail!("new series needs a name");
+    };
+
+    let dir = Path::new(&articles_dir()).join(content_path(name, "series name")?);
+    let summary = dir.join("summary.md");
+    if summary.exists() {
+        bail!("{} already exists", summary.display());
+    }
+
+    let front_matter = toml::to_string(&NewSeries {
+        short_summary: args.flag("summary").unwrap_or_default(),
+        name: "summary",
+    })?;
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(&summary, format!("#####\n{}#####\n\n# {}\n\n", front_matter, name))?;
+    println!("Created {}", summary.display());
+    println!("Add parts with `blogger-cli new article <title> --series {:?}`", name);
+    Ok(0)
+}
+
+/// Lint the content tree; exits 1 on errors, or on warnings with `--strict`
+async fn lint(args: Args) -> Result<i32> {
+    let report = lint_content_tree().await?;
+
+    if args.switch("json") {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for finding in &report.findings {
+            println!("{}", finding);
+        }
+        println!(
+            "{} files checked: {} error(s), {} warning(s)",
+            report.files, report.errors, report.warnings
+        );
+    }
+
+    let failed = !report.passed() || (args.switch("strict") && report.warnings > 0);
+    Ok(i32::from(failed))
+}
+
+/// Merge a CSV export into `reading.toml`, keeping hand-added covers,
+/// notes and review links on books already listed
+fn reading_import(args: Args) -> Result<i32> {
+    let Some(csv) = args.positional.first() else {
+        bail!("reading import needs the path of a CSV export");
+    };
+    let data = std::fs::read_to_string(csv).with_context(|| format!("failed to read {}", csv))?;
+    let books = import_reading_csv(&data).with_context(|| format!("failed to import {}", csv))?;
+
+    let reading_file = content_paths().reading;
+    let mut list = match std::fs::read_to_string(&reading_file) {
+        Ok(content) => ReadingList::from_toml(&content).with_context(|| format!("invalid {}", reading_file))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => ReadingList::default(),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", reading_file)),
+    };
+    let (added, updated) = list.merge(books);
+
+    std::fs::write(&reading_file, list.to_toml()?)?;
+    println!("{}: {} added, {} updated", reading_file, added, updated);
+    Ok(0)
+}
+
+/// Series and their prev/next links as a Graphviz digraph
+fn graph() -> Result<i32> {
+    build_content_index_blocking()?;
+    let index = content_index();
+
+    let mut dot = String::from("digraph articles {\n  rankdir=LR;\n  node [shape=box];\n");
+    for (i, series) in index.all_series(None).iter().enumerate() {
+        let _ = writeln!(dot, "  subgraph cluster_{} {{\n    label={:?};", i, series.name);
+        for article in &series.articles {
+            let _ = writeln!(dot, "    {:?} [label={:?}];", article.metadata.path, article.metadata.title);
+        }
+        dot.push_str("  }\n");
+    }
+
+    for article in index.all_articles() {
+        let Some(ref metadata) = article.toml_metadata else {
+            continue;
+        };
+        for entry in &metadata.article_series {
+            if let Some(ref next) = entry.next {
+                let _ = writeln!(
+                    dot,
+                    "  {:?} -> {:?} [label={:?}];",
+                    article.metadata.path,
+                    format!("{}.md", next),
+                    entry.name
+                );
+            }
+        }
+    }
+    dot.push_str("}\n");
+
+    print!("{}", dot);
+    Ok(0)
+}
+
+/// Build the index the server would build and summarise it
+fn index(args: Args) -> Result<i32> {
+    build_content_index_blocking()?;
+    let index = content_index();
+
+    if args.switch("json") {
+        println!("{}", serde_json::to_string_pretty(&index.listing())?);
+        return Ok(0);
+    }
+
+    println!("{} articles", index.all_articles().count());
+    println!("languages: {}", index.languages().into_iter().collect::<Vec<_>>().join(", "));
+    for series in index.all_series(None) {
+        println!("series {:?}: {} parts", series.name, series.total_articles);
+    }
+    for (tag, count) in index.tag_counts() {
+        println!("tag {:?}: {}", tag, count);
+    }
+    Ok(0)
+}
+
+/// Export using the server binary next to this one unless `--server` is given
+fn export(args: Args) -> Result<i32> {
+    let server = match args.flag("server") {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_exe()?.with_file_name("blogger"),
+    };
+    let out_dir = args
+        .positional
+        .first()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(crate::export::DEFAULT_OUT_DIR));
+
+    crate::export::run(&server, out_dir)?;
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    /// A front-matter file in the temp dir
+    fn article(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("blogger-cli-{}-{}.md", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_args_parse() {
+        let parsed = Args::parse(&args(&["My title", "--series", "Rust", "--json", "extra"]), &["json"]).unwrap();
+        assert_eq!(parsed.positional, ["My title", "extra"]);
+        assert_eq!(parsed.flag("series"), Some("Rust"));
+        assert_eq!(parsed.flag("slug"), None);
+        assert!(parsed.switch("json"));
+        assert!(!parsed.switch("strict"));
+
+        // A flag that isn't a switch needs a value
+        assert!(Args::parse(&args(&["--slug"]), &[]).is_err());
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  Rust  2024: async/await "), "rust-2024-async-await");
+        assert_eq!(slugify("Ça marche"), "ça-marche");
+        assert_eq!(slugify("?!"), "");
+    }
+
+    #[test]
+    fn test_content_path() {
+        assert!(content_path("Rust", "series").is_ok());
+        assert!(content_path("Rust Deep Dive/Part One", "series").is_ok());
+        for value in ["", "..", "../outside", "Rust/../../outside", "/etc", "./Rust"] {
+            assert!(content_path(value, "series").is_err(), "{:?} should be refused", value);
+        }
+    }
+
+    #[test]
+    fn test_part_number() {
+        assert_eq!(part_number(Path::new("Series/03-metrics.md")), Some(3));
+        assert_eq!(part_number(Path::new("10-wrap-up.md")), Some(10));
+        assert_eq!(part_number(Path::new("summary.md")), None);
+        assert_eq!(part_number(Path::new("intro-notes.md")), None);
+    }
+
+    #[test]
+    fn test_link_next_adds_to_matching_entry() {
+        let path = article(
+            "matching",
+            "#####\ndate = \"2024-01-01\"\n\n[[article_series]]\nname = \"Other\"\n\n\
+             [[article_series]]\nname = \"Rust \\\"Deep\\\" Dive\"\nprev = \"Rust/01-intro\"\n#####\n# Two\n",
+        );
+
+        assert!(link_next(&path, "Rust \"Deep\" Dive", "Rust/03-\"next\"").unwrap());
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "#####\ndate = \"2024-01-01\"\n\n[[article_series]]\nname = \"Other\"\n\n\
+             [[article_series]]\nname = \"Rust \\\"Deep\\\" Dive\"\nprev = \"Rust/01-intro\"\n\
+             next = 'Rust/03-\"next\"'\n#####\n# Two\n"
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_link_next_keeps_existing_next() {
+        let path = article(
+            "existing",
+            "#####\n[[article_series]]\nname = \"Rust\"\nnext=\"Rust/03-old\"\n#####\n",
+        );
+        assert!(!link_next(&path, "Rust", "Rust/03-new").unwrap());
+        assert!(!link_next(&path, "Missing", "Rust/03-new").unwrap());
+        std::fs::remove_file(path).unwrap();
+
+        // Only the `next` key itself counts
+        let path = article("similar", "#####\n[[article_series]]\nname = \"Rust\"\nnext_up = \"x\"\n#####\n");
+        assert!(link_next(&path, "Rust", "Rust/03-new").unwrap());
+        assert!(std::fs::read_to_string(&path).unwrap().contains("next_up = \"x\"\nnext = \"Rust/03-new\"\n"));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_new_article_front_matter_is_escaped() {
+        let front_matter = NewArticle {
+            id: "Say \"hi\"/intro".to_string(),
+            date: "2024-01-01".to_string(),
+            authors: vec!["jane"],
+            summary: "",
+            tags: Vec::new(),
+            draft: true,
+            article_series: vec![NewSeriesEntry {
+                name: "Say \"hi\"",
+                prev: Some("Say \"hi\"/01-intro".to_string()),
+            }],
+        };
+        let toml = toml::to_string(&front_matter).unwrap();
+        let parsed: advanced_markdown_parser::ArticleTomlMetadata = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed.id.as_deref(), Some("Say \"hi\"/intro"));
+        assert_eq!(parsed.article_series[0].name, "Say \"hi\"");
+        assert_eq!(parsed.article_series[0].prev.as_deref(), Some("Say \"hi\"/01-intro"));
+        assert!(parsed.draft);
+    }
+}
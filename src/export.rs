@@ -0,0 +1,352 @@
+//! `blogger export [dir]`: pre-render every route into a static site
+//!
+//! Starts the server binary on a local port, requests each route and writes the
+//! server-rendered HTML (with its server-function results embedded for
+//! hydration) to `dir/<route>/index.html`, next to the feeds, the sitemap and
+//! a copy of the bundled client assets.
+//!
+//! Every server-function result the pages load is also saved under `dir/data`
+//! (see [`static_data`]), so routing in the browser keeps working without a server.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use chrono::Datelike;
+use dioxus::logger::tracing;
+
+use crate::markdown_management::authors::load_author_registry;
+use crate::markdown_management::index::content_index;
+use crate::markdown_management::sitemap::encode_segment;
+use crate::markdown_management::static_data::{self, exported, ExportInfo};
+use crate::markdown_management::{
+    build_content_index_blocking, fetch_all_series, fetch_archive, fetch_article_history, fetch_articles_by_term,
+    fetch_home_page_data_with_metadata, fetch_search_index, fetch_search_index_version, fetch_series_by_name,
+    fetch_standalone_articles, fetch_tags, fetch_taxonomy_index, PaginatedArticles, TaxonomyKind,
+};
+
+/// Output directory when none is given
+pub const DEFAULT_OUT_DIR: &str = "dist";
+
+/// Port the temporary server listens on, unless `EXPORT_PORT` is set
+const DEFAULT_PORT: u16 = 8765;
+
+/// How long to wait for the temporary server to come up
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pages that aren't built from content
+const STATIC_ROUTES: &[&str] = &[
+    "/", "/articles", "/series", "/tags", "/archive", "/about", "/reading", "/demos", "/demos/algovis",
+    "/search", "/saved",
+];
+
+/// Non-HTML files copied verbatim from the server
+const FILES: &[&str] = &["/feed.xml", "/atom.xml", "/feed.json", "/sitemap.xml", "/robots.txt"];
+
+/// Taxonomy kinds and the route prefix of their term pages
+const TAXONOMIES: [(TaxonomyKind, &str); 3] = [
+    (TaxonomyKind::Tag, "tags"),
+    (TaxonomyKind::Topic, "topics"),
+    (TaxonomyKind::Category, "category"),
+];
+
+/// A route as raw path segments; the URL is percent-encoded, the file path isn't
+type RoutePath = Vec<String>;
+
+fn route_url(segments: &RoutePath) -> String {
+    let encoded: Vec<String> = segments.iter().map(|s| encode_segment(s)).collect();
+    format!("/{}", encoded.join("/"))
+}
+
+/// Folder a route is written to, refusing segments that would leave `out_dir`
+fn route_dir(out_dir: &Path, segments: &RoutePath) -> Result<PathBuf> {
+    segments.iter().try_fold(out_dir.to_path_buf(), |dir, segment| {
+        if segment.is_empty() || segment == "." || segment == ".." || segment.contains(['/', '\\']) {
+            bail!("Unsafe path segment {:?}", segment);
+        }
+        Ok(dir.join(segment))
+    })
+}
+
+/// Stops the temporary server when the export finishes or fails
+struct ServerGuard(Child);
+
+impl Drop for ServerGuard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Export the whole site into `out_dir`, rendered by the `server` binary
+pub fn run(server: &Path, out_dir: PathBuf) -> Result<()> {
+    build_content_index_blocking().context("Failed to build content index")?;
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(export(server, out_dir))
+}
+
+async fn export(server: &Path, out_dir: PathBuf) -> Result<()> {
+    let port = std::env::var("EXPORT_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_PORT);
+    let base = format!("http://127.0.0.1:{}", port);
+
+    let routes = routes().await?;
+
+    tokio::fs::create_dir_all(&out_dir).await?;
+    let out_dir = out_dir.canonicalize()?;
+    copy_assets(server, &out_dir).await?;
+
+    // Results saved by an earlier export may no longer match the content
+    let data_dir = out_dir.join(static_data::DATA_DIR);
+    if data_dir.is_dir() {
+        tokio::fs::remove_dir_all(&data_dir).await?;
+    }
+    export_data(&out_dir).await?;
+    let export_script = export_script()?;
+
+    // The server saves the results of every page it renders as well
+    let _server = ServerGuard(
+        Command::new(server)
+            .env("IP", "127.0.0.1")
+            .env("PORT", port.to_string())
+            .env("EXPORT_DATA_DIR", &out_dir)
+            .stdout(Stdio::null())
+            .spawn()
+            .context("Failed to start the server")?,
+    );
+    let client = reqwest::Client::new();
+    wait_for_server(&client, &base).await?;
+
+    let mut failed = 0;
+    for route in &routes {
+        let url = route_url(route);
+        let page = match route_dir(&out_dir, route) {
+            Ok(dir) => fetch(&client, &base, &url).await.map(|html| (dir, html)),
+            Err(e) => Err(e),
+        };
+        match page {
+            Ok((dir, html)) => {
+                tokio::fs::create_dir_all(&dir).await?;
+                tokio::fs::write(dir.join("index.html"), inject_script(&html, &export_script)).await?;
+            }
+            Err(e) => {
+                tracing::warn!("Skipping {}: {}", url, e);
+                failed += 1;
+            }
+        }
+    }
+
+    for file in FILES {
+        let body = fetch(&client, &base, file).await?;
+        tokio::fs::write(out_dir.join(file.trim_start_matches('/')), body).await?;
+    }
+
+    tracing::info!(
+        "Exported {} pages to {}",
+        routes.len() - failed,
+        out_dir.display()
+    );
+    if failed > 0 {
+        bail!("{} pages failed to render", failed);
+    }
+    Ok(())
+}
+
+/// Every route worth a page: static pages, articles (drafts excluded),
+/// series, tags, topics, categories, archive years and months, and authors
+async fn routes() -> Result<Vec<RoutePath>> {
+    let registry = load_author_registry().await.map_err(anyhow::Error::msg)?;
+    let single = |route: &str| -> RoutePath {
+        route.split('/').filter(|s| !s.is_empty()).map(String::from).collect()
+    };
+
+    let mut routes: Vec<RoutePath> = STATIC_ROUTES.iter().map(|route| single(route)).collect();
+    let index = content_index();
+
+    let mut months = BTreeSet::new();
+    for article in index.all_articles() {
+        let metadata = article.toml_metadata.clone().unwrap_or_default();
+        if metadata.draft {
+            continue;
+        }
+        if let Some(date) = metadata.date {
+            months.insert((date.date().year(), date.date().month()));
+        }
+
+        let path = article.metadata.path.trim_end_matches(".md");
+        routes.push(std::iter::once("article").chain(path.split('/')).map(String::from).collect());
+    }
+
+    for series in index.all_series(None) {
+        routes.push(vec!["series".to_string(), series.name]);
+    }
+    for (kind, prefix) in TAXONOMIES {
+        routes.extend(index.term_counts(kind).into_keys().map(|term| vec![prefix.to_string(), term]));
+    }
+
+    let years: BTreeSet<i32> = months.iter().map(|(year, _)| *year).collect();
+    routes.extend(years.into_iter().map(|year| vec!["archive".to_string(), year.to_string()]));
+    routes.extend(
+        months
+            .into_iter()
+            .map(|(year, month)| vec!["archive".to_string(), year.to_string(), month.to_string()]),
+    );
+
+    routes.extend(registry.authors.into_keys().map(|id| vec!["author".to_string(), id]));
+    Ok(routes)
+}
+
+/// Save the results pages only load in the browser, or only in some languages
+///
+/// The server render of each route covers its own data in the default
+/// language. This adds every listing page in every language, the search
+/// index and each article's history.
+async fn export_data(out_dir: &Path) -> Result<()> {
+    static_data::record_into(out_dir.to_path_buf())?;
+
+    let (languages, series, terms, article_paths) = {
+        let index = content_index();
+        let series: Vec<String> = index.all_series(None).into_iter().map(|series| series.name).collect();
+        let terms: Vec<(TaxonomyKind, String)> = TAXONOMIES
+            .iter()
+            .flat_map(|(kind, _)| index.term_counts(*kind).into_keys().map(move |term| (*kind, term)))
+            .collect();
+        let article_paths: Vec<String> = index
+            .all_articles()
+            .filter(|article| !article.toml_metadata.as_ref().is_some_and(|metadata| metadata.draft))
+            .map(|article| article.metadata.path.clone())
+            .collect();
+        (index.languages(), series, terms, article_paths)
+    };
+
+    let per_page = PaginatedArticles::PER_PAGE;
+    let locales = std::iter::once(None).chain(languages.into_iter().map(Some));
+    for locale in locales {
+        let home_locale = locale.clone();
+        exported!(fetch_home_page_data_with_metadata(home_locale)).await.map_err(anyhow::Error::msg)?;
+        let series_locale = locale.clone();
+        exported!(fetch_all_series(series_locale)).await.map_err(anyhow::Error::msg)?;
+        for name in &series {
+            let (name, series_locale) = (name.clone(), locale.clone());
+            exported!(fetch_series_by_name(name, series_locale)).await.map_err(anyhow::Error::msg)?;
+        }
+
+        let archive = {
+            let (year, month, archive_locale) = (None, None, locale.clone());
+            exported!(fetch_archive(year, month, archive_locale)).await.map_err(anyhow::Error::msg)?
+        };
+        for archive_year in archive {
+            let (year, month, archive_locale) = (Some(archive_year.year), None, locale.clone());
+            exported!(fetch_archive(year, month, archive_locale)).await.map_err(anyhow::Error::msg)?;
+            for archive_month in archive_year.months {
+                let (month, archive_locale) = (Some(archive_month.month), locale.clone());
+                exported!(fetch_archive(year, month, archive_locale)).await.map_err(anyhow::Error::msg)?;
+            }
+        }
+
+        let mut page = 1;
+        loop {
+            let page_locale = locale.clone();
+            let articles = exported!(fetch_standalone_articles(page, per_page, page_locale))
+                .await
+                .map_err(anyhow::Error::msg)?;
+            if page >= articles.total_pages {
+                break;
+            }
+            page += 1;
+        }
+
+        for (kind, term) in &terms {
+            let mut page = 1;
+            loop {
+                let (kind, term, page_locale) = (*kind, term.clone(), locale.clone());
+                let articles = exported!(fetch_articles_by_term(kind, term, page, per_page, page_locale))
+                    .await
+                    .map_err(anyhow::Error::msg)?;
+                if page >= articles.total_pages {
+                    break;
+                }
+                page += 1;
+            }
+        }
+    }
+
+    exported!(fetch_taxonomy_index()).await.map_err(anyhow::Error::msg)?;
+    exported!(fetch_tags()).await.map_err(anyhow::Error::msg)?;
+    exported!(fetch_search_index_version()).await.map_err(anyhow::Error::msg)?;
+    exported!(fetch_search_index()).await.map_err(anyhow::Error::msg)?;
+    for path in article_paths {
+        exported!(fetch_article_history(path)).await.map_err(anyhow::Error::msg)?;
+    }
+    Ok(())
+}
+
+/// Tells the client it runs from a static copy, and in which languages
+fn export_script() -> Result<String> {
+    let info = ExportInfo {
+        languages: content_index().languages().into_iter().collect(),
+    };
+    Ok(format!(
+        "<script>window.bloggerExport = () => JSON.stringify({});</script>",
+        serde_json::to_string(&info)?
+    ))
+}
+
+/// Copy the bundled client (`public/` next to the server, as built by `dx bundle`)
+async fn copy_assets(server: &Path, out_dir: &Path) -> Result<()> {
+    let public = server.parent().map(|dir| dir.join("public")).unwrap_or_default();
+    if !public.is_dir() {
+        bail!(
+            "No client assets at {}; run the exporter from `dx bundle --web` output",
+            public.display()
+        );
+    }
+
+    let mut pending = vec![(public, out_dir.to_path_buf())];
+    while let Some((from, to)) = pending.pop() {
+        tokio::fs::create_dir_all(&to).await?;
+        let mut entries = tokio::fs::read_dir(&from).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let target = to.join(entry.file_name());
+            if entry.file_type().await?.is_dir() {
+                pending.push((entry.path(), target));
+            } else {
+                tokio::fs::copy(entry.path(), target).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn wait_for_server(client: &reqwest::Client, base: &str) -> Result<()> {
+    let started = std::time::Instant::now();
+    while started.elapsed() < STARTUP_TIMEOUT {
+        if client.get(format!("{}/robots.txt", base)).send().await.is_ok() {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    bail!("Server did not start within {:?}", STARTUP_TIMEOUT)
+}
+
+async fn fetch(client: &reqwest::Client, base: &str, url: &str) -> Result<String> {
+    let response = client.get(format!("{}{}", base, url)).send().await?;
+    if !response.status().is_success() {
+        bail!("HTTP {}", response.status());
+    }
+    Ok(response.text().await?)
+}
+
+fn inject_script(html: &str, script: &str) -> String {
+    match html.find("</head>") {
+        Some(at) => format!("{}{}{}", &html[..at], script, &html[at..]),
+        None => format!("{}{}", script, html),
+    }
+}
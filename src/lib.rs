@@ -0,0 +1,9 @@
+//! Content handling shared by the web app and the `blogger-cli` authoring tool
+
+pub mod markdown_management;
+
+#[cfg(feature = "server")]
+pub mod cli;
+
+#[cfg(feature = "server")]
+pub mod export;
@@ -1,11 +1,15 @@
+use advanced_markdown_parser::encode_segment;
+use crate::markdown_management::TaxonomyKind;
+use crate::shared::loading::PageSuspense;
 use crate::shared::nav_bar::NavBar;
+use crate::shared::seo::{ArticleSeo, PageSeo};
 use dioxus::{
     document::eval,
     logger::{self, tracing::Level},
     prelude::*,
 };
 
-pub mod markdown_management;
+pub use blogger::markdown_management;
 pub mod pages;
 pub mod shared;
 
@@ -29,19 +33,78 @@ enum Route {
     SeriesDetail { name: String },
     #[route("/articles")]
     Articles {},
+    #[route("/author/:id")]
+    Author { id: String },
     #[route("/demos/algovis")]
     AlgoVis {},
+    #[route("/search?:query")]
+    Search { query: String },
+    #[route("/saved")]
+    Saved {},
+    #[route("/tags")]
+    Tags {},
+    #[route("/tags/:tag")]
+    Tag { tag: String },
+    #[route("/topics/:topic")]
+    Topic { topic: String },
+    #[route("/category/:category")]
+    Category { category: String },
+    #[route("/archive")]
+    Archive {},
+    #[route("/archive/:year")]
+    ArchiveYear { year: i32 },
+    #[route("/archive/:year/:month")]
+    ArchiveMonth { year: i32, month: u32 },
 }
 
 fn main() {
-    // Initialize article watcher on server startup
+    // Site settings pick the content paths, so they load before exporting or indexing
     #[cfg(feature = "server")]
     {
-        if let Err(e) = markdown_management::start_article_watcher() {
+        let _ = logger::init(Level::INFO);
+        if let Err(e) = markdown_management::init_site_config() {
+            logger::tracing::error!("Failed to load site settings: {}", e);
+        }
+    }
+
+    // `blogger export [dir]` writes a static copy of the site instead of serving it
+    #[cfg(feature = "server")]
+    if std::env::args().nth(1).as_deref() == Some("export") {
+        let out_dir = std::env::args().nth(2).unwrap_or_else(|| blogger::export::DEFAULT_OUT_DIR.to_string());
+        let result = std::env::current_exe()
+            .map_err(anyhow::Error::from)
+            .and_then(|server| blogger::export::run(&server, out_dir.into()));
+        if let Err(e) = result {
+            logger::tracing::error!("Export failed: {:#}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Index all articles and start the watcher that keeps the index current;
+    // Ctrl+C and SIGTERM stop it before the process exits
+    #[cfg(feature = "server")]
+    {
+        if let Err(e) = markdown_management::build_content_index_blocking() {
+            logger::tracing::error!("Failed to build content index: {}", e);
+        }
+
+        let watcher = markdown_management::WatcherService::start(markdown_management::WatcherConfig::from_env())
+            .and_then(markdown_management::WatcherService::shutdown_on_signal);
+        if let Err(e) = watcher {
             logger::tracing::error!("Failed to start article watcher: {}", e);
         }
     }
 
+    // Feeds, the sitemap and robots.txt are plain HTTP routes alongside the app
+    #[cfg(feature = "server")]
+    dioxus::serve(|| async move {
+        Ok(dioxus::server::router(App)
+            .merge(markdown_management::feeds::router())
+            .merge(markdown_management::sitemap::router()))
+    });
+
+    #[cfg(not(feature = "server"))]
     dioxus::launch(App);
 }
 
@@ -49,6 +112,9 @@ fn main() {
 fn App() -> Element {
     let _ = logger::init(Level::INFO);
 
+    // Reader's preferred language for article listings
+    shared::locale::use_locale_provider();
+
     // Set initial theme
     use_effect(move || {
         eval(r#"
@@ -63,6 +129,24 @@ fn App() -> Element {
             rel: "stylesheet",
             href: TAILWIND_CSS,
         }
+        document::Link {
+            rel: "alternate",
+            r#type: "application/rss+xml",
+            title: "RSS",
+            href: "/feed.xml",
+        }
+        document::Link {
+            rel: "alternate",
+            r#type: "application/atom+xml",
+            title: "Atom",
+            href: "/atom.xml",
+        }
+        document::Link {
+            rel: "alternate",
+            r#type: "application/feed+json",
+            title: "JSON Feed",
+            href: "/feed.json",
+        }
         document::Meta {
             name: "viewport",
             content: "width=device-width, initial-scale=1.0, maximum-scale=5.0",
@@ -88,7 +172,10 @@ fn Home() -> Element {
         div {
             class: "min-h-dvh flex flex-col",
             NavBar {}
-            pages::home_page::HomePage {}
+            PageSeo { path: "/".to_string(), title: None }
+            PageSuspense {
+                pages::home_page::HomePage {}
+            }
         }
     }
 }
@@ -110,6 +197,7 @@ fn Article(segments: Vec<String>) -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
+            ArticleSeo { path: full_path.clone() }
             // Use key to force component remount on path change
             // This ensures all hooks re-initialize with the new path
             pages::article_page::ArticlePage {
@@ -126,7 +214,10 @@ fn About() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
-            pages::about_page::AboutPage {}
+            PageSeo { path: "/about".to_string(), title: Some("About".to_string()) }
+            PageSuspense {
+                pages::about_page::AboutPage {}
+            }
         }
     }
 }
@@ -137,6 +228,7 @@ fn Demos() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
+            PageSeo { path: "/demos".to_string(), title: Some("Demos".to_string()) }
             pages::demos_page::DemosPage {}
         }
     }
@@ -148,7 +240,10 @@ fn Reading() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
-            pages::reading_page::ReadingPage {}
+            PageSeo { path: "/reading".to_string(), title: Some("Reading".to_string()) }
+            PageSuspense {
+                pages::reading_page::ReadingPage {}
+            }
         }
     }
 }
@@ -159,7 +254,10 @@ fn Series() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
-            pages::series_page::SeriesPage {}
+            PageSeo { path: "/series".to_string(), title: Some("Series".to_string()) }
+            PageSuspense {
+                pages::series_page::SeriesPage {}
+            }
         }
     }
 }
@@ -170,8 +268,15 @@ fn SeriesDetail(name: String) -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
-            pages::series_detail_page::SeriesDetailPage {
-                series_name: name
+            PageSeo {
+                path: format!("/series/{}", encode_segment(&name)),
+                title: Some(name.clone()),
+                description: format!("Every part of the {} series", name)
+            }
+            PageSuspense {
+                pages::series_detail_page::SeriesDetailPage {
+                    series_name: name
+                }
             }
         }
     }
@@ -183,7 +288,25 @@ fn Articles() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
-            pages::articles_page::ArticlesPage {}
+            PageSeo { path: "/articles".to_string(), title: Some("Articles".to_string()) }
+            PageSuspense {
+                pages::articles_page::ArticlesPage {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Author(id: String) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSuspense {
+                pages::author_page::AuthorPage {
+                    author_id: id
+                }
+            }
         }
     }
 }
@@ -194,7 +317,156 @@ fn AlgoVis() -> Element {
         div {
             class: "h-dvh flex flex-col overflow-hidden",
             NavBar {}
+            PageSeo { path: "/demos/algovis".to_string(), title: Some("Algorithm Visualiser".to_string()) }
             pages::algo_vis_page::AlgoVisPage {}
         }
     }
 }
+
+#[component]
+fn Search(query: String) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: "/search".to_string(), title: Some("Search".to_string()), noindex: true }
+            // Remount on a new query from the nav bar so the page's input resets
+            pages::search_page::SearchPage {
+                key: "{query}",
+                query
+            }
+        }
+    }
+}
+
+#[component]
+fn Saved() -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: "/saved".to_string(), title: Some("Saved articles".to_string()), noindex: true }
+            pages::saved_page::SavedPage {}
+        }
+    }
+}
+
+#[component]
+fn Tags() -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: "/tags".to_string(), title: Some("Tags, topics and categories".to_string()) }
+            PageSuspense {
+                pages::taxonomy_page::TaxonomyIndexPage {}
+            }
+        }
+    }
+}
+
+#[component]
+fn Tag(tag: String) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: format!("/tags/{}", encode_segment(&tag)), title: Some(format!("Tagged \"{}\"", tag)) }
+            PageSuspense {
+                pages::taxonomy_page::TaxonomyPage {
+                    key: "{tag}",
+                    kind: TaxonomyKind::Tag,
+                    term: tag
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Topic(topic: String) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: format!("/topics/{}", encode_segment(&topic)), title: Some(format!("Topic: {}", topic)) }
+            PageSuspense {
+                pages::taxonomy_page::TaxonomyPage {
+                    key: "{topic}",
+                    kind: TaxonomyKind::Topic,
+                    term: topic
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Category(category: String) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: format!("/category/{}", encode_segment(&category)), title: Some(category.clone()) }
+            PageSuspense {
+                pages::taxonomy_page::TaxonomyPage {
+                    key: "{category}",
+                    kind: TaxonomyKind::Category,
+                    term: category
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Archive() -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: "/archive".to_string(), title: Some("Archive".to_string()) }
+            PageSuspense {
+                pages::archive_page::ArchivePage {}
+            }
+        }
+    }
+}
+
+#[component]
+fn ArchiveYear(year: i32) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo { path: format!("/archive/{}", year), title: Some(format!("Archive · {}", year)) }
+            PageSuspense {
+                pages::archive_page::ArchivePage {
+                    key: "{year}",
+                    year
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ArchiveMonth(year: i32, month: u32) -> Element {
+    rsx! {
+        div {
+            class: "h-dvh flex flex-col overflow-hidden",
+            NavBar {}
+            PageSeo {
+                path: format!("/archive/{}/{}", year, month),
+                title: Some(format!("Archive · {} {}", pages::archive_page::month_name(month), year))
+            }
+            PageSuspense {
+                pages::archive_page::ArchivePage {
+                    key: "{year}-{month}",
+                    year,
+                    month
+                }
+            }
+        }
+    }
+}
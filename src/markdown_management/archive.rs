@@ -0,0 +1,127 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use advanced_markdown_parser::ArticleDate;
+
+#[cfg(feature = "server")]
+use super::index::content_index;
+
+/// A published article as listed in the archive
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveEntry {
+    pub path: String,
+    pub title: String,
+    pub date: ArticleDate,
+    pub series: Option<String>,
+}
+
+/// Articles published in one month, newest first
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveMonth {
+    /// 1 to 12
+    pub month: u32,
+    pub entries: Vec<ArchiveEntry>,
+}
+
+/// Articles published in one year, grouped by month, newest first
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveYear {
+    pub year: i32,
+    pub count: usize,
+    pub months: Vec<ArchiveMonth>,
+}
+
+/// Clear the cached archive after content changes (server-side)
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_archive() {
+    use cached::Cached;
+
+    ARCHIVE_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime (server-side)
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    ARCHIVE_CACHE.lock().await.cache_set_lifespan(seconds);
+}
+
+/// Fetch every dated article, series parts included, grouped by year and month
+/// Narrowed to one year, or one month of it, when given
+#[server]
+pub async fn fetch_archive(
+    year: Option<i32>,
+    month: Option<u32>,
+    locale: Option<String>,
+) -> Result<Vec<ArchiveYear>, ServerFnError> {
+    let lang = content_index().resolve_locale(locale.as_deref());
+    let mut archive = cached_archive(lang).await?;
+
+    if let Some(year) = year {
+        archive.retain(|y| y.year == year);
+    }
+    if let Some(month) = month {
+        for archive_year in &mut archive {
+            archive_year.months.retain(|m| m.month == month);
+            archive_year.count = archive_year.months.iter().map(|m| m.entries.len()).sum();
+        }
+        archive.retain(|y| y.count > 0);
+    }
+
+    Ok(archive)
+}
+
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "ARCHIVE_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ lang.clone() }"#
+)]
+async fn cached_archive(lang: String) -> Result<Vec<ArchiveYear>, ServerFnError> {
+    use chrono::Datelike;
+    use std::collections::BTreeMap;
+
+    // Undated articles have no place in a chronological view
+    let mut entries: Vec<ArchiveEntry> = content_index()
+        .localized(Some(&lang))
+        .into_iter()
+        .filter_map(|article| {
+            let metadata = article.toml_metadata?;
+            Some(ArchiveEntry {
+                date: metadata.date?,
+                series: metadata.primary_series.or_else(|| metadata.series.first().cloned()),
+                path: article.metadata.path,
+                title: article.metadata.title,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.path.cmp(&b.path)));
+
+    let mut years: BTreeMap<i32, BTreeMap<u32, Vec<ArchiveEntry>>> = BTreeMap::new();
+    for entry in entries {
+        let date = entry.date.date();
+        years
+            .entry(date.year())
+            .or_default()
+            .entry(date.month())
+            .or_default()
+            .push(entry);
+    }
+
+    Ok(years
+        .into_iter()
+        .rev()
+        .map(|(year, months)| ArchiveYear {
+            year,
+            count: months.values().map(Vec::len).sum(),
+            months: months
+                .into_iter()
+                .rev()
+                .map(|(month, entries)| ArchiveMonth { month, entries })
+                .collect(),
+        })
+        .collect())
+}
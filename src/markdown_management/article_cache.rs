@@ -0,0 +1,26 @@
+use netabase_store::netabase_definition_module;
+
+#[netabase_definition_module(ArticleCacheDefinition, ArticleCacheKeys)]
+pub mod article_cache_schema {
+    use netabase_store::{netabase, NetabaseModel};
+
+    /// An article stored in IndexedDB for offline reading
+    /// The article is stored as JSON so new fields don't invalidate old entries
+    #[derive(NetabaseModel, Clone, Debug, bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]
+    #[netabase(ArticleCacheDefinition)]
+    pub struct CachedArticle {
+        #[primary_key]
+        pub path: String,
+        pub title: String,
+        /// Series the article was saved with
+        pub series: Option<String>,
+        /// Saved explicitly by the reader, rather than cached while reading
+        #[secondary_key]
+        pub saved: bool,
+        pub article_json: String,
+        pub cached_at: f64, // JavaScript timestamp
+    }
+}
+
+// Re-export the generated types
+pub use article_cache_schema::*;
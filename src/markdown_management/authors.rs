@@ -0,0 +1,218 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+#[cfg(feature = "server")]
+use advanced_markdown_parser::ArticleTomlMetadata;
+
+use super::local::ArticleWithMetadata;
+#[cfg(feature = "server")]
+use super::site::content_paths;
+
+/// A link shown on an author's profile (GitHub, Mastodon, website, ...)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthorLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// An entry in the `authors.toml` registry
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Author {
+    /// Registry key, filled in after loading
+    #[serde(default)]
+    pub id: String,
+    /// Display name
+    pub name: String,
+    #[serde(default)]
+    pub bio: Option<String>,
+    /// Avatar image URL
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default)]
+    pub links: Vec<AuthorLink>,
+}
+
+/// The site's author registry, keyed by author ID
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthorRegistry {
+    /// ID of the site owner, shown on the home page
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub authors: BTreeMap<String, Author>,
+}
+
+impl AuthorRegistry {
+    /// Look up an author by ID
+    pub fn get(&self, id: &str) -> Option<&Author> {
+        self.authors.get(id)
+    }
+
+    /// The site owner, if configured
+    pub fn owner(&self) -> Option<&Author> {
+        self.owner.as_deref().and_then(|id| self.get(id))
+    }
+
+    /// Resolve the authors referenced by an article's front matter
+    ///
+    /// `authors` entries must be registry IDs. The legacy free-text `author`
+    /// field is matched against IDs and then display names. Unknown values
+    /// are returned separately so callers can report them.
+    #[cfg(feature = "server")]
+    pub fn resolve(&self, metadata: &ArticleTomlMetadata) -> (Vec<Author>, Vec<String>) {
+        let mut resolved: Vec<Author> = Vec::new();
+        let mut unknown = Vec::new();
+
+        for id in &metadata.authors {
+            match self.get(id) {
+                Some(author) => resolved.push(author.clone()),
+                None => unknown.push(id.clone()),
+            }
+        }
+
+        if let Some(ref name) = metadata.author {
+            let by_id_or_name = self
+                .get(name)
+                .or_else(|| self.authors.values().find(|a| a.name == *name));
+            if let Some(author) = by_id_or_name {
+                if !resolved.iter().any(|a| a.id == author.id) {
+                    resolved.push(author.clone());
+                }
+            }
+        }
+
+        (resolved, unknown)
+    }
+}
+
+/// Everything shown on an author's page
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthorPageData {
+    pub author: Author,
+    /// Articles by this author, most recent first
+    pub articles: Vec<ArticleWithMetadata>,
+    /// Names of series this author has written for
+    pub series: Vec<String>,
+}
+
+/// Load and validate `authors.toml` (a missing file is an empty registry)
+/// Cached until the watcher reports a change to the file
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "AUTHOR_REGISTRY_CACHE", time = 21600, result = true, sync_writes = true)]
+pub(crate) async fn load_author_registry() -> Result<AuthorRegistry, String> {
+    let path = content_paths().authors;
+    let content = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(AuthorRegistry::default()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path, e)),
+    };
+
+    let mut registry: AuthorRegistry =
+        toml::from_str(&content).map_err(|e| format!("Invalid {}: {}", path, e))?;
+
+    for (id, author) in registry.authors.iter_mut() {
+        author.id = id.clone();
+    }
+
+    if let Some(ref owner) = registry.owner {
+        if registry.get(owner).is_none() {
+            dioxus::logger::tracing::warn!("{}: owner '{}' is not a registered author", path, owner);
+        }
+    }
+
+    Ok(registry)
+}
+
+/// Fetch every registered author
+#[server]
+pub async fn fetch_authors() -> Result<Vec<Author>, ServerFnError> {
+    let registry = load_author_registry().await.map_err(ServerFnError::new)?;
+    Ok(registry.authors.into_values().collect())
+}
+
+/// Fetch the site owner shown on the home page
+#[server]
+pub async fn fetch_site_owner() -> Result<Author, ServerFnError> {
+    let registry = load_author_registry().await.map_err(ServerFnError::new)?;
+    registry
+        .owner()
+        .cloned()
+        .ok_or_else(|| ServerFnError::new("No site owner configured in authors.toml"))
+}
+
+/// Fetch an author with their articles and series
+#[server]
+pub async fn fetch_author_page(id: String) -> Result<AuthorPageData, ServerFnError> {
+    cached_author_page(id).await
+}
+
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "AUTHOR_PAGE_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ id.clone() }"#
+)]
+async fn cached_author_page(id: String) -> Result<AuthorPageData, ServerFnError> {
+    use super::index::content_index;
+    use super::local::compare_by_date_desc;
+
+    let registry = load_author_registry().await.map_err(ServerFnError::new)?;
+    let author = registry
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| ServerFnError::ServerError {
+            message: format!("Unknown author: {}", id),
+            code: 404,
+            details: None,
+        })?;
+
+    let mut articles: Vec<ArticleWithMetadata> = content_index()
+        .localized(None)
+        .into_iter()
+        .filter(|article| article.authors.iter().any(|a| a.id == id))
+        .collect();
+
+    articles.sort_by(compare_by_date_desc);
+
+    let mut series: Vec<String> = articles
+        .iter()
+        .filter_map(|a| a.toml_metadata.as_ref())
+        .flat_map(|m| m.primary_series.iter().chain(m.series.iter()).cloned())
+        .collect();
+    series.sort();
+    series.dedup();
+
+    Ok(AuthorPageData {
+        author,
+        articles,
+        series,
+    })
+}
+
+/// Drop cached author pages after articles change
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_author_pages() {
+    use cached::Cached;
+
+    AUTHOR_PAGE_CACHE.lock().await.cache_clear();
+}
+
+/// Drop the cached registry after `authors.toml` changes
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_author_registry() {
+    use cached::Cached;
+
+    AUTHOR_REGISTRY_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime to the registry and author pages
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    AUTHOR_REGISTRY_CACHE.lock().await.cache_set_lifespan(seconds);
+    AUTHOR_PAGE_CACHE.lock().await.cache_set_lifespan(seconds);
+}
@@ -0,0 +1,297 @@
+//! RSS, Atom and JSON Feed endpoints built from the content index
+//!
+//! `/feed.xml`, `/atom.xml` and `/feed.json` all accept the same query:
+//! `content=full|summary`, `series=<name>`, `tag=<tag>` and `lang=<code>`.
+
+use std::path::Path;
+
+use advanced_markdown_parser::{Feed, FeedContent, FeedItem, DEFAULT_LANGUAGE};
+use dioxus::server::axum::{
+    extract::Query,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+
+use super::authors::load_author_registry;
+use super::index::content_index;
+use super::local::{translation_base, ArticleWithMetadata};
+use super::site::site_url;
+use super::sitemap::encode_segment;
+
+/// Most recent articles included in a feed
+const FEED_LENGTH: usize = 50;
+
+/// Date component of the `tag:` URIs used as GUIDs (RFC 4151)
+/// Must never change, or every reader sees every article as new
+const TAG_URI_DATE: &str = "2025";
+
+/// Query accepted by every feed endpoint
+#[derive(Clone, Debug, Default, Deserialize)]
+pub(crate) struct FeedQuery {
+    content: Option<String>,
+    series: Option<String>,
+    tag: Option<String>,
+    lang: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    fn path(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "/feed.xml",
+            FeedFormat::Atom => "/atom.xml",
+            FeedFormat::Json => "/feed.json",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// Feed routes to merge into the app's router
+pub fn router() -> Router {
+    Router::new()
+        .route(FeedFormat::Rss.path(), get(|query: Query<FeedQuery>| serve(FeedFormat::Rss, query)))
+        .route(FeedFormat::Atom.path(), get(|query: Query<FeedQuery>| serve(FeedFormat::Atom, query)))
+        .route(FeedFormat::Json.path(), get(|query: Query<FeedQuery>| serve(FeedFormat::Json, query)))
+}
+
+async fn serve(format: FeedFormat, Query(query): Query<FeedQuery>) -> Response {
+    let content = match query.content.as_deref() {
+        None => FeedContent::Full,
+        Some(value) => match FeedContent::parse(value) {
+            Some(content) => content,
+            None => return (StatusCode::BAD_REQUEST, "content must be \"full\" or \"summary\"").into_response(),
+        },
+    };
+
+    // Resolve before caching so arbitrary query values can't grow the cache
+    let (lang, series, tag) = {
+        let index = content_index();
+        let lang = index.resolve_locale(query.lang.as_deref());
+        let tag = query
+            .tag
+            .map(|tag| index.taxonomy().canonical_tag(&tag).map(str::to_string).unwrap_or(tag));
+
+        let unknown_series = query.series.as_ref().is_some_and(|name| index.series(name, None).is_none());
+        let unknown_tag = tag.as_ref().is_some_and(|tag| !index.tag_counts().contains_key(tag));
+        if unknown_series || unknown_tag {
+            return (StatusCode::NOT_FOUND, "No such series or tag").into_response();
+        }
+
+        (lang, query.series, tag)
+    };
+
+    match cached_feed(format.path(), content, series, tag, lang).await {
+        Ok(feed) => {
+            let body = match format {
+                FeedFormat::Rss => feed.to_rss(),
+                FeedFormat::Atom => feed.to_atom(),
+                FeedFormat::Json => feed.to_json(),
+            };
+            ([(header::CONTENT_TYPE, format.content_type())], body).into_response()
+        }
+        Err(e) => {
+            dioxus::logger::tracing::error!("Failed to build feed: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to build feed").into_response()
+        }
+    }
+}
+
+/// Clear cached feeds after content changes
+pub(crate) async fn invalidate_feeds() {
+    use cached::Cached;
+
+    FEED_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    FEED_CACHE.lock().await.cache_set_lifespan(seconds);
+}
+
+/// Build a feed of the newest articles, narrowed to a series and/or tag
+#[cached::proc_macro::cached(
+    name = "FEED_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ format!("{}|{:?}|{:?}|{:?}|{}", path, content, series, tag, lang) }"#
+)]
+async fn cached_feed(
+    path: &'static str,
+    content: FeedContent,
+    series: Option<String>,
+    tag: Option<String>,
+    lang: String,
+) -> Result<Feed, String> {
+    let registry = load_author_registry().await?;
+    let owner = registry.owner().cloned();
+    let site = site_url();
+
+    let articles = {
+        let index = content_index();
+        let mut articles = index.localized(Some(&lang));
+
+        if let Some(data) = series.as_ref().and_then(|name| index.series(name, Some(&lang))) {
+            articles = data.articles;
+        }
+        if let Some(ref tag) = tag {
+            articles.retain(|article| {
+                article.toml_metadata.as_ref().is_some_and(|m| m.tags.contains(tag))
+            });
+        }
+        articles
+    };
+
+    let mut items: Vec<FeedItem> = articles
+        .iter()
+        .filter_map(|article| feed_item(article, content, &site))
+        .collect();
+    items.sort_by(|a, b| b.published.cmp(&a.published).then_with(|| a.id.cmp(&b.id)));
+    items.truncate(FEED_LENGTH);
+
+    let owner_name = owner.as_ref().map(|o| o.name.clone());
+    let site_title = owner_name.clone().unwrap_or_else(|| "Articles".to_string());
+    let (title, home_page_url) = match (&series, &tag) {
+        (Some(name), _) => (
+            format!("{} · {}", site_title, name),
+            format!("{}/series/{}", site, encode_segment(name)),
+        ),
+        (None, Some(tag)) => (
+            format!("{} · #{}", site_title, tag),
+            format!("{}/tags/{}", site, encode_segment(tag)),
+        ),
+        (None, None) => (site_title, site.clone()),
+    };
+
+    let mut query: Vec<String> = Vec::new();
+    if content == FeedContent::Summary {
+        query.push("content=summary".to_string());
+    }
+    query.extend(series.iter().map(|s| format!("series={}", encode_segment(s))));
+    query.extend(tag.iter().map(|t| format!("tag={}", encode_segment(t))));
+    if lang != DEFAULT_LANGUAGE {
+        query.push(format!("lang={}", encode_segment(&lang)));
+    }
+    let feed_url = if query.is_empty() {
+        format!("{}{}", site, path)
+    } else {
+        format!("{}{}?{}", site, path, query.join("&"))
+    };
+
+    Ok(Feed {
+        title,
+        description: owner
+            .and_then(|o| o.bio)
+            .unwrap_or_else(|| "Latest articles".to_string()),
+        home_page_url,
+        feed_url,
+        language: lang,
+        author: owner_name,
+        items,
+    })
+}
+
+/// A feed entry for an article; undated, draft and unlisted articles are left out
+fn feed_item(article: &ArticleWithMetadata, content: FeedContent, site: &str) -> Option<FeedItem> {
+    let metadata = article.toml_metadata.as_ref().filter(|m| m.is_listed())?;
+    let published = metadata.date?;
+
+    let summary = metadata
+        .summary
+        .clone()
+        .or_else(|| (!article.excerpt.is_empty()).then(|| article.excerpt.clone()));
+    let content_html = match content {
+        FeedContent::Full => Some(markdown::to_html(&article.content)),
+        FeedContent::Summary => None,
+    };
+
+    let mut authors: Vec<String> = article.authors.iter().map(|a| a.name.clone()).collect();
+    if authors.is_empty() {
+        authors.extend(metadata.author.clone());
+    }
+
+    Some(FeedItem {
+        id: article_guid(article, site),
+        url: format!("{}/article/{}", site, encode_path(article.metadata.path.trim_end_matches(".md"))),
+        title: article.metadata.title.clone(),
+        summary,
+        content_html,
+        published,
+        updated: metadata.updated,
+        authors,
+        tags: metadata.tags.clone(),
+    })
+}
+
+/// `tag:` URI identifying an article across renames
+///
+/// Uses the front matter `id` when set, else the translation base path.
+/// Translations get the language appended so each version is its own entry.
+fn article_guid(article: &ArticleWithMetadata, site: &str) -> String {
+    let (base, lang) = translation_base(Path::new(&article.metadata.path));
+    let id = article
+        .toml_metadata
+        .as_ref()
+        .and_then(|m| m.id.clone())
+        .unwrap_or_else(|| base.to_string_lossy().into_owned());
+    tag_uri(site, &id, &lang)
+}
+
+/// `tag:` URI for article `id` in `lang`, under the site's host name
+fn tag_uri(site: &str, id: &str, lang: &str) -> String {
+    let authority = site
+        .split("://")
+        .last()
+        .unwrap_or(site)
+        .split(['/', ':'])
+        .next()
+        .unwrap_or_default();
+
+    if lang == DEFAULT_LANGUAGE {
+        format!("tag:{},{}:article/{}", authority, TAG_URI_DATE, encode_path(id))
+    } else {
+        format!("tag:{},{}:article/{}/{}", authority, TAG_URI_DATE, encode_path(id), encode_segment(lang))
+    }
+}
+
+/// A slash-separated path with each segment percent-encoded, as in the sitemap
+fn encode_path(path: &str) -> String {
+    path.split('/').map(encode_segment).collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_links_are_encoded() {
+        assert_eq!(encode_path("Project Management/02-people"), "Project%20Management/02-people");
+        assert_eq!(
+            tag_uri("https://example.com:8080/blog", "Project Management/02-people", DEFAULT_LANGUAGE),
+            "tag:example.com,2025:article/Project%20Management/02-people"
+        );
+        assert_eq!(
+            tag_uri("https://example.com", "notes/C# & F#", "pt-BR"),
+            "tag:example.com,2025:article/notes/C%23%20%26%20F%23/pt-BR"
+        );
+    }
+}
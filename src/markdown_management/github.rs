@@ -23,8 +23,10 @@ pub struct GitHubRepo {
 }
 
 /// GitHub user/org type
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum GitHubAccountType {
+    #[default]
     User,
     Organization,
 }
@@ -0,0 +1,111 @@
+use dioxus::prelude::*;
+
+pub use advanced_markdown_parser::ArticleRevision;
+#[cfg(feature = "server")]
+use advanced_markdown_parser::{
+    commit_date_format, parse_last_commit_dates, parse_revisions, revision_format, ArticleDate, ArticleId,
+};
+#[cfg(feature = "server")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "server")]
+use super::local::resolve_article;
+#[cfg(feature = "server")]
+use super::site::articles_dir;
+
+/// Diffs longer than this are cut short so huge rewrites don't swamp the page
+#[cfg(feature = "server")]
+const MAX_DIFF_LINES: usize = 400;
+
+/// Run `git` against the local repository (never touches the network)
+#[cfg(feature = "server")]
+async fn run_git(args: &[&str]) -> Option<String> {
+    let output = tokio::process::Command::new("git")
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| dioxus::logger::tracing::warn!("Failed to run git: {}", e))
+        .ok()?;
+
+    if !output.status.success() {
+        dioxus::logger::tracing::warn!(
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Newest commit date of every tracked file under the articles directory,
+/// keyed by path relative to it, from a single `git log` pass
+///
+/// Empty when the content isn't inside a git checkout.
+#[cfg(feature = "server")]
+pub(crate) async fn last_commit_dates() -> BTreeMap<String, ArticleDate> {
+    let root = articles_dir();
+    let format = commit_date_format();
+    let output = run_git(&[
+        "-C",
+        &root,
+        "-c",
+        "core.quotePath=false",
+        "log",
+        "--name-only",
+        "--relative",
+        &format,
+        "--",
+        ".",
+    ])
+    .await;
+
+    output.map(|output| parse_last_commit_dates(&output)).unwrap_or_default()
+}
+
+/// Fetch the commits that touched an article, newest first (server-side)
+/// Returns an empty history when the content isn't inside a git checkout
+#[server]
+pub async fn fetch_article_history(path: String) -> Result<Vec<ArticleRevision>, ServerFnError> {
+    cached_article_history(resolve_article(&path)?).await
+}
+
+/// Apply the configured cache lifetime
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    ARTICLE_HISTORY_CACHE.lock().await.cache_set_lifespan(seconds);
+}
+
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "ARTICLE_HISTORY_CACHE",
+    time = 300,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ id.to_string() }"#
+)]
+async fn cached_article_history(id: ArticleId) -> Result<Vec<ArticleRevision>, ServerFnError> {
+    let file_path = format!("{}/{}", articles_dir(), id);
+
+    let format = revision_format();
+    let Some(output) = run_git(&[
+        "log",
+        "--follow",
+        "--patch",
+        "--no-color",
+        "--no-ext-diff",
+        &format,
+        "--",
+        &file_path,
+    ])
+    .await
+    else {
+        return Ok(Vec::new());
+    };
+
+    Ok(parse_revisions(&output, MAX_DIFF_LINES))
+}
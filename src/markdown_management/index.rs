@@ -0,0 +1,552 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use advanced_markdown_parser::{
+    near_duplicate_terms, negotiate_language, SearchDocument, SearchFilters, SearchIndex, SearchResult, Taxonomy,
+    DEFAULT_LANGUAGE,
+};
+use futures::future::join_all;
+
+use super::history::last_commit_dates;
+use super::site::{articles_dir, content_paths};
+use super::taxonomy::TaxonomyKind;
+use super::local::{
+    collect_markdown_files_sync, load_article, relative_article_path, translation_base,
+    ArticleMetadata, ArticleWithMetadata, ContentChanges, SeriesData, SeriesSummaryMetadata,
+};
+
+/// Short and long descriptions from a series' `summary.md`
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SeriesSummary {
+    pub(crate) short: Option<String>,
+    pub(crate) long: Option<String>,
+}
+
+/// In-memory view of every article, built once at startup and kept current by the watcher
+#[derive(Default)]
+pub(crate) struct ContentIndex {
+    /// Every article file, keyed by its path relative to the articles directory
+    articles: BTreeMap<String, ArticleWithMetadata>,
+    /// Paths of each article's language versions, keyed by translation base
+    groups: BTreeMap<String, Vec<String>>,
+    /// Translation bases of the articles in each series
+    series: BTreeMap<String, BTreeSet<String>>,
+    /// Translation bases of the articles carrying each tag
+    tags: BTreeMap<String, BTreeSet<String>>,
+    /// Translation bases of the articles covering each topic
+    topics: BTreeMap<String, BTreeSet<String>>,
+    /// Translation bases of the articles in each category
+    categories: BTreeMap<String, BTreeSet<String>>,
+    /// `summary.md` contents keyed by series name
+    summaries: BTreeMap<String, SeriesSummary>,
+    /// Full-text index over every article
+    search: SearchIndex,
+    /// Canonical tags and categories from `taxonomy.toml`
+    taxonomy: Taxonomy,
+}
+
+static CONTENT_INDEX: LazyLock<RwLock<ContentIndex>> = LazyLock::new(Default::default);
+
+/// Read access to the content index
+/// Don't hold the guard across an `.await`
+pub(crate) fn content_index() -> RwLockReadGuard<'static, ContentIndex> {
+    CONTENT_INDEX.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn content_index_mut() -> RwLockWriteGuard<'static, ContentIndex> {
+    CONTENT_INDEX.write().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Translation base shared by all language versions (`rust/01-intro.fr.md` -> `rust/01-intro`)
+fn group_key(path: &str) -> String {
+    translation_base(Path::new(path)).0.to_string_lossy().into_owned()
+}
+
+/// Whether an article appears in listings, series, taxonomy pages and search
+/// Drafts and unlisted articles are only reachable by their URL
+fn is_listed(article: &ArticleWithMetadata) -> bool {
+    article.toml_metadata.as_ref().is_none_or(|metadata| metadata.is_listed())
+}
+
+/// Series summaries live in `summary.md` and are not articles
+fn is_summary_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|s| s.to_str())
+        .is_some_and(|s| s.contains("summary"))
+}
+
+impl ContentIndex {
+    /// Recompute translation groups, series membership and tags from the articles
+    /// Drafts and unlisted articles keep their translation group but are left
+    /// out of series, taxonomy terms and search
+    fn reindex(&mut self) {
+        self.groups.clear();
+        self.series.clear();
+        self.tags.clear();
+        self.topics.clear();
+        self.categories.clear();
+
+        for (path, article) in &self.articles {
+            let key = group_key(path);
+            self.groups.entry(key.clone()).or_default().push(path.clone());
+
+            if let Some(ref metadata) = article.toml_metadata
+                && metadata.is_listed()
+            {
+                for name in metadata.primary_series.iter().chain(metadata.series.iter()) {
+                    self.series.entry(name.clone()).or_default().insert(key.clone());
+                }
+                for tag in &metadata.tags {
+                    self.tags.entry(tag.clone()).or_default().insert(key.clone());
+                }
+                for topic in &metadata.topics {
+                    self.topics.entry(topic.clone()).or_default().insert(key.clone());
+                }
+                if let Some(ref category) = metadata.category {
+                    self.categories.entry(category.clone()).or_default().insert(key.clone());
+                }
+            }
+        }
+
+        self.summaries.retain(|name, _| self.series.contains_key(name));
+        self.search = SearchIndex::build(self.articles.values().filter(|a| is_listed(a)).map(search_document));
+    }
+
+    /// Every language some article is written in
+    pub(crate) fn languages(&self) -> BTreeSet<String> {
+        self.articles
+            .values()
+            .map(|article| article.metadata.lang.clone())
+            .collect()
+    }
+
+    /// Map a reader's locale onto one of the site's languages
+    /// Keeps cache keys bounded no matter what browsers send
+    pub(crate) fn resolve_locale(&self, locale: Option<&str>) -> String {
+        let languages = self.languages();
+        let available: Vec<&str> = languages.iter().map(String::as_str).collect();
+
+        negotiate_language(locale, &available)
+            .unwrap_or(DEFAULT_LANGUAGE)
+            .to_string()
+    }
+
+    /// Series an article file belongs to
+    fn series_of(&self, path: &str) -> Vec<String> {
+        self.articles
+            .get(path)
+            .and_then(|article| article.toml_metadata.as_ref())
+            .map(|metadata| {
+                metadata
+                    .primary_series
+                    .iter()
+                    .chain(metadata.series.iter())
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Every article file, translations included
+    pub(crate) fn all_articles(&self) -> impl Iterator<Item = &ArticleWithMetadata> {
+        self.articles.values()
+    }
+
+    /// Whether no articles are indexed (none exist, or the build failed)
+    pub(crate) fn is_empty(&self) -> bool {
+        self.articles.is_empty()
+    }
+
+    /// A single article file by path
+    pub(crate) fn article(&self, path: &str) -> Option<ArticleWithMetadata> {
+        self.articles.get(path).cloned()
+    }
+
+    /// The listed language version of an article best matching `locale`
+    fn localized_version(&self, paths: &[String], locale: Option<&str>) -> Option<&ArticleWithMetadata> {
+        let versions: Vec<&ArticleWithMetadata> = paths
+            .iter()
+            .filter_map(|path| self.articles.get(path))
+            .filter(|article| is_listed(article))
+            .collect();
+        let available: Vec<&str> = versions.iter().map(|a| a.metadata.lang.as_str()).collect();
+
+        let lang = negotiate_language(locale, &available)?;
+        versions.into_iter().find(|a| a.metadata.lang == lang)
+    }
+
+    /// One entry per listed article in its default language, sorted by name
+    pub(crate) fn listing(&self) -> Vec<ArticleMetadata> {
+        let mut listing: Vec<ArticleMetadata> = self
+            .groups
+            .iter()
+            .filter_map(|(base, paths)| {
+                let article = self.localized_version(paths, None)?;
+                let name = Path::new(base)
+                    .file_name()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+
+                Some(ArticleMetadata {
+                    name,
+                    ..article.metadata.clone()
+                })
+            })
+            .collect();
+
+        listing.sort_by(|a, b| a.name.cmp(&b.name));
+        listing
+    }
+
+    /// Every listed article, each in the language best matching `locale`
+    pub(crate) fn localized(&self, locale: Option<&str>) -> Vec<ArticleWithMetadata> {
+        self.groups
+            .values()
+            .filter_map(|paths| self.localized_version(paths, locale))
+            .cloned()
+            .collect()
+    }
+
+    fn series_data(&self, name: &str, bases: &BTreeSet<String>, locale: Option<&str>) -> SeriesData {
+        let mut articles: Vec<ArticleWithMetadata> = bases
+            .iter()
+            .filter_map(|base| self.groups.get(base))
+            .filter_map(|paths| self.localized_version(paths, locale))
+            .cloned()
+            .collect();
+
+        // Sort articles by name within the series
+        articles.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+        let summary = self.summaries.get(name).cloned().unwrap_or_default();
+
+        SeriesData {
+            name: name.to_string(),
+            total_articles: articles.len(),
+            articles,
+            short_summary: summary.short,
+            long_summary: summary.long,
+        }
+    }
+
+    /// A single series with its articles localized to `locale`
+    pub(crate) fn series(&self, name: &str, locale: Option<&str>) -> Option<SeriesData> {
+        let bases = self.series.get(name)?;
+        Some(self.series_data(name, bases, locale))
+    }
+
+    /// Every series sorted by name, with articles localized to `locale`
+    pub(crate) fn all_series(&self, locale: Option<&str>) -> Vec<SeriesData> {
+        self.series
+            .iter()
+            .map(|(name, bases)| self.series_data(name, bases, locale))
+            .collect()
+    }
+
+    /// Canonical tags and categories articles are normalised against
+    pub(crate) fn taxonomy(&self) -> &Taxonomy {
+        &self.taxonomy
+    }
+
+    /// Number of articles carrying each tag
+    pub(crate) fn tag_counts(&self) -> BTreeMap<String, usize> {
+        self.term_counts(TaxonomyKind::Tag)
+    }
+
+    fn terms(&self, kind: TaxonomyKind) -> &BTreeMap<String, BTreeSet<String>> {
+        match kind {
+            TaxonomyKind::Tag => &self.tags,
+            TaxonomyKind::Topic => &self.topics,
+            TaxonomyKind::Category => &self.categories,
+        }
+    }
+
+    /// Whether any article is filed under `term`
+    pub(crate) fn has_term(&self, kind: TaxonomyKind, term: &str) -> bool {
+        self.terms(kind).contains_key(term)
+    }
+
+    /// Number of articles for each tag, topic or category
+    pub(crate) fn term_counts(&self, kind: TaxonomyKind) -> BTreeMap<String, usize> {
+        self.terms(kind)
+            .iter()
+            .map(|(term, bases)| (term.clone(), bases.len()))
+            .collect()
+    }
+
+    /// Articles filed under `term`, localized to `locale`, or `None` for an unknown term
+    pub(crate) fn articles_for_term(
+        &self,
+        kind: TaxonomyKind,
+        term: &str,
+        locale: Option<&str>,
+    ) -> Option<Vec<ArticleWithMetadata>> {
+        let bases = self.terms(kind).get(term)?;
+        Some(
+            bases
+                .iter()
+                .filter_map(|base| self.groups.get(base))
+                .filter_map(|paths| self.localized_version(paths, locale))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// The full-text index, for shipping to the browser
+    pub(crate) fn search_index(&self) -> &SearchIndex {
+        &self.search
+    }
+
+    /// Ranked full-text matches for `query`
+    pub(crate) fn search(&self, query: &str, filters: &SearchFilters, limit: usize) -> Vec<SearchResult> {
+        self.search.search(query, filters, limit)
+    }
+}
+
+/// What the search index needs to know about an article
+fn search_document(article: &ArticleWithMetadata) -> SearchDocument {
+    let metadata = article.toml_metadata.clone().unwrap_or_default();
+
+    SearchDocument {
+        id: article.metadata.path.clone(),
+        title: article.metadata.title.clone(),
+        summary: metadata.summary,
+        tags: metadata.tags,
+        series: metadata.primary_series.into_iter().chain(metadata.series).collect(),
+        category: metadata.category,
+        lang: article.metadata.lang.clone(),
+        date: metadata.date,
+        body: article.content.clone(),
+    }
+}
+
+/// Read `summary.md` from a series folder (missing files give an empty summary)
+async fn read_series_summary(series_name: &str) -> SeriesSummary {
+    let summary_path = format!("{}/{}/summary.md", articles_dir(), series_name);
+    let Ok(content) = tokio::fs::read_to_string(&summary_path).await else {
+        return SeriesSummary::default();
+    };
+
+    let (summary, error) = parse_series_summary(&content);
+    if let Some(e) = error {
+        dioxus::logger::tracing::warn!("{}: invalid front matter: {}", summary_path, e);
+    }
+    summary
+}
+
+/// Split `summary.md` into its short (front matter) and long descriptions,
+/// along with any error in the front matter
+pub(crate) fn parse_series_summary(content: &str) -> (SeriesSummary, Option<toml::de::Error>) {
+    // Parse TOML frontmatter and markdown content
+    if content.starts_with("#####") {
+        let parts: Vec<&str> = content.splitn(3, "#####").collect();
+        if parts.len() >= 3 {
+            let toml_str = parts[1].trim();
+            let markdown_content = parts[2].trim();
+
+            let metadata: Result<SeriesSummaryMetadata, _> = toml::from_str(toml_str);
+            let (short, error) = match metadata {
+                Ok(metadata) => (metadata.short_summary, None),
+                Err(e) => (None, Some(e)),
+            };
+
+            let summary = SeriesSummary {
+                short,
+                long: Some(markdown_content.to_string()),
+            };
+            return (summary, error);
+        }
+    }
+
+    let summary = SeriesSummary {
+        short: None,
+        long: Some(content.to_string()),
+    };
+    (summary, None)
+}
+
+/// Read `taxonomy.toml` (a missing or invalid file gives an empty taxonomy)
+async fn load_taxonomy() -> Taxonomy {
+    let path = content_paths().taxonomy;
+    let Ok(content) = tokio::fs::read_to_string(&path).await else {
+        return Taxonomy::default();
+    };
+
+    Taxonomy::from_toml(&content).unwrap_or_else(|e| {
+        dioxus::logger::tracing::warn!("{}: {}", path, e);
+        Taxonomy::default()
+    })
+}
+
+/// Warn about tags and topics in use that look like variants of each other
+fn warn_near_duplicate_terms(index: &ContentIndex) {
+    let terms = index.tags.keys().chain(index.topics.keys()).map(String::as_str);
+    for (a, b) in near_duplicate_terms(terms) {
+        dioxus::logger::tracing::warn!(
+            "Tags \"{}\" and \"{}\" look like the same tag; add one as an alias in {}",
+            a,
+            b,
+            content_paths().taxonomy
+        );
+    }
+}
+
+/// Load articles in parallel; `None` marks files that are gone or unreadable
+async fn load_articles(paths: Vec<String>) -> Vec<(String, Option<ArticleWithMetadata>)> {
+    // One `git log` for every file rather than one per article
+    let commit_dates = &last_commit_dates().await;
+
+    let futures = paths.into_iter().map(|path| async move {
+        let last_commit = commit_dates.get(&path).copied();
+        let article = match load_article(path.clone(), last_commit).await {
+            Ok(article) => Some(article),
+            Err(e) => {
+                if Path::new(&articles_dir()).join(&path).exists() {
+                    dioxus::logger::tracing::warn!("Failed to index {}: {}", path, e);
+                }
+                None
+            }
+        };
+        (path, article)
+    });
+
+    join_all(futures).await
+}
+
+async fn load_summaries(names: Vec<String>) -> Vec<(String, SeriesSummary)> {
+    let futures = names.into_iter().map(|name| async move {
+        let summary = read_series_summary(&name).await;
+        (name, summary)
+    });
+
+    join_all(futures).await
+}
+
+/// Load every article and series summary and replace the index
+pub(crate) async fn build_content_index() -> std::io::Result<()> {
+    let start = std::time::Instant::now();
+
+    let root = articles_dir();
+    let files = collect_markdown_files_sync(Path::new(&root))?;
+    let paths = files
+        .iter()
+        .map(|file| relative_article_path(&file.path, &root))
+        .collect();
+
+    // Articles are normalised against the live index's taxonomy while loading
+    let taxonomy = load_taxonomy().await;
+    content_index_mut().taxonomy = taxonomy.clone();
+
+    let mut index = ContentIndex {
+        taxonomy,
+        ..Default::default()
+    };
+    for (path, article) in load_articles(paths).await {
+        if let Some(article) = article {
+            index.articles.insert(path, article);
+        }
+    }
+    index.reindex();
+    warn_near_duplicate_terms(&index);
+
+    let names = index.series.keys().cloned().collect();
+    index.summaries = load_summaries(names).await.into_iter().collect();
+
+    let count = index.articles.len();
+    *content_index_mut() = index;
+
+    dioxus::logger::tracing::info!("Built content index: {} articles in {:?}", count, start.elapsed());
+    Ok(())
+}
+
+/// Build the index on a temporary runtime, before the server starts
+pub fn build_content_index_blocking() -> std::io::Result<()> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(build_content_index())
+}
+
+/// Rebuild the whole index and report everything as changed
+pub(crate) async fn rebuild_content_index() -> std::io::Result<ContentChanges> {
+    build_content_index().await?;
+
+    Ok(ContentChanges {
+        rebuilt: true,
+        ..Default::default()
+    })
+}
+
+/// Bring the index up to date after files changed, reporting what was touched
+/// `changed` holds paths relative to the articles directory
+pub(crate) async fn update_content_index(changed: Vec<PathBuf>) -> std::io::Result<ContentChanges> {
+    let mut article_paths = BTreeSet::new();
+    let mut summary_series = BTreeSet::new();
+
+    for path in changed {
+        if Path::new(&articles_dir()).join(&path).is_dir() {
+            // Whole folders were added, moved or removed
+            return rebuild_content_index().await;
+        }
+
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("md") if is_summary_file(&path) => {
+                let series = path.parent().map(|p| p.to_string_lossy().into_owned());
+                summary_series.extend(series.filter(|s| !s.is_empty()));
+            }
+            Some("md") => {
+                let path = path.to_string_lossy().into_owned();
+
+                // Adding or removing a translation changes every version's translation list
+                let siblings = content_index().groups.get(&group_key(&path)).cloned();
+                article_paths.extend(siblings.unwrap_or_default());
+                article_paths.insert(path);
+            }
+            // A removed folder no longer shows up as a directory
+            None if !Path::new(&articles_dir()).join(&path).exists() => {
+                return rebuild_content_index().await;
+            }
+            _ => {}
+        }
+    }
+
+    let mut changes = ContentChanges {
+        series: summary_series.clone(),
+        ..Default::default()
+    };
+
+    if !article_paths.is_empty() {
+        let loaded = load_articles(article_paths.iter().cloned().collect()).await;
+
+        let mut index = content_index_mut();
+        for (path, article) in loaded {
+            // Series the article leaves and joins are both affected
+            changes.series.extend(index.series_of(&path));
+            match article {
+                Some(article) => index.articles.insert(path.clone(), article),
+                None => index.articles.remove(&path),
+            };
+            changes.series.extend(index.series_of(&path));
+        }
+        index.reindex();
+
+        changes.articles = article_paths;
+    }
+
+    // Reload changed summaries and pick up series that have none yet
+    let names: Vec<String> = {
+        let index = content_index();
+        index
+            .series
+            .keys()
+            .filter(|name| summary_series.contains(*name) || !index.summaries.contains_key(*name))
+            .cloned()
+            .collect()
+    };
+    let summaries = load_summaries(names).await;
+    content_index_mut().summaries.extend(summaries);
+
+    Ok(changes)
+}
@@ -0,0 +1,136 @@
+use dioxus::prelude::*;
+
+use advanced_markdown_parser::LintReport;
+#[cfg(feature = "server")]
+use advanced_markdown_parser::{lint_articles, LintFinding, LintRule, LintSource, Taxonomy};
+
+#[cfg(feature = "server")]
+use super::authors::load_author_registry;
+#[cfg(feature = "server")]
+use super::index::parse_series_summary;
+#[cfg(feature = "server")]
+use super::local::{collect_markdown_files_sync, relative_article_path};
+#[cfg(feature = "server")]
+use super::reading::load_reading_list;
+#[cfg(feature = "server")]
+use super::site::{articles_dir, content_paths, site_languages};
+
+/// Check every article, series summary and site-level content file
+///
+/// Reads the files on disk rather than the index, so it also reports
+/// problems in articles the index failed to load.
+#[server]
+pub async fn lint_content() -> Result<LintReport, ServerFnError> {
+    lint_content_tree()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Failed to lint content: {}", e)))
+}
+
+/// Lint `articles/`, `taxonomy.toml`, `authors.toml` and `reading.toml`
+///
+/// Article and summary paths are relative to `articles/`; site-level files
+/// are named as they are.
+#[cfg(feature = "server")]
+pub(crate) async fn lint_content_tree() -> std::io::Result<LintReport> {
+    let root = articles_dir();
+    let mut sources = Vec::new();
+    let mut series = std::collections::BTreeSet::new();
+
+    for file in collect_markdown_files_sync(std::path::Path::new(&root))? {
+        let path = relative_article_path(&file.path, &root);
+        let content = tokio::fs::read_to_string(&file.path).await?;
+        if let Some((folder, _)) = path.rsplit_once('/') {
+            series.insert(folder.to_string());
+        }
+        sources.push(LintSource { path, content });
+    }
+
+    let (taxonomy, mut findings) = lint_manifests().await;
+    findings.extend(lint_articles(&sources, &taxonomy, &site_languages(), image_exists));
+    for name in series {
+        findings.extend(lint_series_summary(&name).await);
+    }
+
+    Ok(LintReport::new(sources.len(), findings))
+}
+
+/// Load the taxonomy the way the index does, reporting files that fail to parse
+#[cfg(feature = "server")]
+async fn lint_manifests() -> (Taxonomy, Vec<LintFinding>) {
+    let mut findings = Vec::new();
+    let paths = content_paths();
+
+    let taxonomy = match tokio::fs::read_to_string(&paths.taxonomy).await {
+        Ok(content) => Taxonomy::from_toml(&content).unwrap_or_else(|e| {
+            findings.push(LintFinding::error(&paths.taxonomy, LintRule::Manifest, e.to_string()));
+            Taxonomy::default()
+        }),
+        Err(_) => Taxonomy::default(),
+    };
+
+    if let Err(e) = load_author_registry().await {
+        findings.push(LintFinding::error(&paths.authors, LintRule::Manifest, e));
+    }
+
+    match load_reading_list().await {
+        Ok(list) => {
+            // Review links use the same form as series links, without `.md`
+            let articles = std::path::PathBuf::from(articles_dir());
+            for book in list.books {
+                if let Some(review) = book.review
+                    && !articles.join(format!("{}.md", review)).is_file()
+                {
+                    findings.push(LintFinding::error(
+                        &paths.reading,
+                        LintRule::Manifest,
+                        format!("review of \"{}\" links to missing article {}", book.title, review),
+                    ));
+                }
+            }
+        }
+        Err(e) => findings.push(LintFinding::error(&paths.reading, LintRule::Manifest, e)),
+    }
+
+    (taxonomy, findings)
+}
+
+/// Every series folder needs a `summary.md` with a `short_summary`
+#[cfg(feature = "server")]
+async fn lint_series_summary(name: &str) -> Vec<LintFinding> {
+    let path = format!("{}/summary.md", name);
+    let Ok(content) = tokio::fs::read_to_string(std::path::Path::new(&articles_dir()).join(&path)).await else {
+        return vec![LintFinding::error(
+            &path,
+            LintRule::MissingSummary,
+            format!("series \"{}\" has no summary.md", name),
+        )];
+    };
+
+    match parse_series_summary(&content) {
+        (_, Some(e)) => vec![LintFinding::error(
+            &path,
+            LintRule::FrontMatter,
+            format!("front matter is not valid TOML: {}", e.message()),
+        )],
+        (summary, None) if summary.short.is_none() => vec![LintFinding::warning(
+            &path,
+            LintRule::MissingSummary,
+            "no short_summary for the series listing".to_string(),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Root-relative images are looked up in the public directory, others next to the article
+#[cfg(feature = "server")]
+fn image_exists(article: &str, src: &str) -> bool {
+    let src = src.split(['?', '#']).next().unwrap_or(src);
+    let file = match src.strip_prefix('/') {
+        Some(src) => std::path::Path::new(&content_paths().public).join(src),
+        None => {
+            let folder = article.rsplit_once('/').map(|(folder, _)| folder).unwrap_or("");
+            std::path::Path::new(&articles_dir()).join(folder).join(src)
+        }
+    };
+    file.is_file()
+}
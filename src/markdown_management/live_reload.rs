@@ -0,0 +1,85 @@
+use dioxus::fullstack::ServerEvents;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use super::local::ContentChanges;
+
+/// Files that changed on disk, pushed to readers while live reload is on
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentChanged {
+    /// Article paths relative to the articles directory
+    pub articles: Vec<String>,
+    /// The whole index was rebuilt, so anything may have changed
+    pub all: bool,
+}
+
+impl ContentChanged {
+    /// Whether the article at `path` should be re-fetched
+    pub fn affects(&self, path: &str) -> bool {
+        self.all || self.articles.iter().any(|changed| changed == path)
+    }
+}
+
+/// Live reload is on in debug builds, or when `LIVE_RELOAD` is set to `1`/`true`
+#[cfg(feature = "server")]
+fn live_reload_enabled() -> bool {
+    match std::env::var("LIVE_RELOAD") {
+        Ok(value) => matches!(value.as_str(), "1" | "true"),
+        Err(_) => cfg!(debug_assertions),
+    }
+}
+
+#[cfg(feature = "server")]
+static CHANGES: std::sync::LazyLock<tokio::sync::broadcast::Sender<ContentChanged>> =
+    std::sync::LazyLock::new(|| tokio::sync::broadcast::channel(16).0);
+
+/// Notify connected readers about changed articles (no-op when live reload is off)
+#[cfg(feature = "server")]
+pub(crate) fn publish_changes(changes: &ContentChanges) {
+    if !live_reload_enabled() || (changes.articles.is_empty() && !changes.rebuilt) {
+        return;
+    }
+
+    // Sending only fails when nobody is listening
+    let _ = CHANGES.send(ContentChanged {
+        articles: changes.articles.iter().cloned().collect(),
+        all: changes.rebuilt,
+    });
+}
+
+/// Stream article changes to the client as server-sent events
+/// Fails straight away when live reload is off, so clients stop listening
+#[server]
+pub async fn watch_content_changes() -> Result<ServerEvents<ContentChanged>, ServerFnError> {
+    use tokio::sync::broadcast::error::RecvError;
+
+    if !live_reload_enabled() {
+        return Err(ServerFnError::new("Live reload is disabled"));
+    }
+
+    let mut receiver = CHANGES.subscribe();
+
+    Ok(ServerEvents::new(|mut tx| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(change) => {
+                    if tx.send(change).await.is_err() {
+                        break;
+                    }
+                }
+                // Missed some changes; ask the client to re-fetch whatever it shows
+                Err(RecvError::Lagged(_)) => {
+                    let change = ContentChanged {
+                        articles: Vec::new(),
+                        all: true,
+                    };
+                    if tx.send(change).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }))
+}
@@ -1,8 +1,25 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
-use advanced_markdown_parser::ArticleTomlMetadata;
+use advanced_markdown_parser::{negotiate_language, ArticleTomlMetadata, DEFAULT_LANGUAGE};
+
+use super::authors::Author;
+#[cfg(feature = "server")]
+use advanced_markdown_parser::{
+    parse_markdown_with_metadata, split_language_suffix, ArticleDate, ArticleId, ArticlePathError,
+};
 #[cfg(feature = "server")]
-use advanced_markdown_parser::parse_markdown_with_metadata;
+use super::index::content_index;
+#[cfg(feature = "server")]
+use super::site::{articles_dir, site_config, CacheConfig};
+
+/// One language version of an article
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Translation {
+    /// Language code (e.g., "en", "fr")
+    pub lang: String,
+    /// Path relative to the articles directory
+    pub path: String,
+}
 
 /// Metadata for an article (basic file info)
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -10,6 +27,28 @@ pub struct ArticleMetadata {
     pub name: String,
     pub path: String,
     pub title: String,
+    /// Language of this file, from its `.xx.md` suffix
+    #[serde(default = "default_language")]
+    pub lang: String,
+    /// Every language version of this article, including this one
+    #[serde(default)]
+    pub translations: Vec<Translation>,
+}
+
+fn default_language() -> String {
+    DEFAULT_LANGUAGE.to_string()
+}
+
+impl ArticleMetadata {
+    /// Path of the version best matching `locale`, falling back to the default language
+    pub fn path_for_locale(&self, locale: Option<&str>) -> String {
+        let available: Vec<&str> = self.translations.iter().map(|t| t.lang.as_str()).collect();
+
+        negotiate_language(locale, &available)
+            .and_then(|lang| self.translations.iter().find(|t| t.lang == lang))
+            .map(|t| t.path.clone())
+            .unwrap_or_else(|| self.path.clone())
+    }
 }
 
 /// Series/group information for organizing related articles
@@ -35,44 +74,92 @@ pub struct ArticleWithMetadata {
     pub metadata: ArticleMetadata,
     pub toml_metadata: Option<ArticleTomlMetadata>,
     pub content: String,
+    /// Authors resolved from the `authors.toml` registry
+    #[serde(default)]
+    pub authors: Vec<Author>,
+    /// Plain-text opening of the article, shown when there is no summary
+    #[serde(default)]
+    pub excerpt: String,
 }
 
+/// Length of the excerpts stored in the content index
+#[cfg(feature = "server")]
+const EXCERPT_LENGTH: usize = 200;
+
 // Parsing functions are now in advanced_markdown_parser crate
 
-/// Extract title from markdown file efficiently (reads only first ~500 bytes)
+/// A markdown file found under the articles directory
 #[cfg(feature = "server")]
-async fn extract_title_from_file(path: &std::path::Path) -> Result<String, std::io::Error> {
-    use tokio::fs::File;
-    use tokio::io::{AsyncBufReadExt, BufReader};
-
-    let file = File::open(path).await?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-
-    // Read first 10 lines to find title (usually in first few lines)
-    let mut line_count = 0;
-    while let Some(line) = lines.next_line().await? {
-        line_count += 1;
-        if line.trim().starts_with("# ") {
-            return Ok(line.trim_start_matches("# ").trim().to_string());
-        }
-        // Stop after 10 lines to avoid reading too much
-        if line_count >= 10 {
-            break;
-        }
+pub(crate) struct ArticleFile {
+    pub(crate) path: std::path::PathBuf,
+    /// Path without language suffix and extension, shared by all translations
+    pub(crate) base: std::path::PathBuf,
+    /// Language from the `.xx.md` suffix, or the default language
+    pub(crate) lang: String,
+}
+
+#[cfg(feature = "server")]
+impl ArticleFile {
+    pub(crate) fn new(path: std::path::PathBuf) -> Self {
+        let (base, lang) = translation_base(&path);
+        ArticleFile { path, base, lang }
     }
+}
 
-    Err(std::io::Error::new(
-        std::io::ErrorKind::NotFound,
-        "No title found",
-    ))
+/// Split an article path into its translation base and language
+/// (`a/intro.fr.md` -> `a/intro`, `fr`; `a/intro.md` -> `a/intro`, default language)
+///
+/// Only the languages listed in `site.toml` are recognised as suffixes.
+#[cfg(feature = "server")]
+pub(crate) fn translation_base(path: &std::path::Path) -> (std::path::PathBuf, String) {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    let (base, lang) = split_language_suffix(stem, &site_config().languages);
+
+    (
+        path.with_file_name(base),
+        lang.unwrap_or(DEFAULT_LANGUAGE).to_string(),
+    )
+}
+
+/// Path relative to the articles directory, as used in URLs and server function arguments
+#[cfg(feature = "server")]
+pub(crate) fn relative_article_path(path: &std::path::Path, base_dir: &str) -> String {
+    path.strip_prefix(base_dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Every language version of the article at `path`, including itself
+#[cfg(feature = "server")]
+fn find_translations(path: &std::path::Path, base_dir: &str) -> Vec<Translation> {
+    let (base, _) = translation_base(path);
+    let Some(entries) = path.parent().and_then(|dir| std::fs::read_dir(dir).ok()) else {
+        return Vec::new();
+    };
+
+    let mut translations: Vec<Translation> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| ArticleFile::new(entry.path()))
+        .filter(|file| {
+            file.base == base && file.path.extension().and_then(|s| s.to_str()) == Some("md")
+        })
+        .map(|file| Translation {
+            lang: file.lang,
+            path: relative_article_path(&file.path, base_dir),
+        })
+        .collect();
+
+    translations.sort_by(|a, b| a.lang.cmp(&b.lang));
+    translations
 }
 
 /// Recursively collect all markdown files from a directory (synchronous)
+/// Translations (`name.xx.md`) are tagged with their language
 #[cfg(feature = "server")]
-fn collect_markdown_files_sync(
+pub(crate) fn collect_markdown_files_sync(
     dir: &std::path::Path,
-) -> Result<Vec<std::path::PathBuf>, std::io::Error> {
+) -> Result<Vec<ArticleFile>, std::io::Error> {
     use std::fs;
 
     let mut markdown_files = Vec::new();
@@ -92,7 +179,7 @@ fn collect_markdown_files_sync(
                 .and_then(|s| s.to_str())
                 .is_some_and(|s| s.contains("summary"))
         {
-            markdown_files.push(path);
+            markdown_files.push(ArticleFile::new(path));
         }
     }
 
@@ -118,116 +205,227 @@ fn extract_series_from_path(path: &std::path::Path, base_dir: &str) -> Option<St
     None
 }
 
-/// List all available article files (server-side)
+/// Order articles by publication date, most recent first
+/// Undated articles go last, ordered by name
+#[cfg(feature = "server")]
+pub(crate) fn compare_by_date_desc(a: &ArticleWithMetadata, b: &ArticleWithMetadata) -> std::cmp::Ordering {
+    let date_a = a.toml_metadata.as_ref().and_then(|m| m.date);
+    let date_b = b.toml_metadata.as_ref().and_then(|m| m.date);
+
+    date_b
+        .cmp(&date_a)
+        .then_with(|| a.metadata.name.cmp(&b.metadata.name))
+}
+
+// Cached results below live for hours: the article watcher invalidates the
+// affected entries through `invalidate_caches` as soon as files change.
+
+/// What an index update touched, used to invalidate cached results
+#[cfg(feature = "server")]
+#[derive(Debug, Default)]
+pub(crate) struct ContentChanges {
+    /// Article paths (relative to the articles directory) that changed
+    pub(crate) articles: std::collections::BTreeSet<String>,
+    /// Series whose membership or summary changed
+    pub(crate) series: std::collections::BTreeSet<String>,
+    /// `aboutme.md` changed
+    pub(crate) about_me: bool,
+    /// The whole index was rebuilt
+    pub(crate) rebuilt: bool,
+}
+
+/// Drop exactly the cached results affected by `changes`
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_caches(changes: &ContentChanges) {
+    use cached::Cached;
+
+    if changes.rebuilt {
+        ARTICLE_CACHE.lock().await.cache_clear();
+        ARTICLE_CONTENT_CACHE.lock().await.cache_clear();
+        SERIES_CACHE.lock().await.cache_clear();
+    }
+
+    for path in &changes.articles {
+        ARTICLE_CACHE.lock().await.cache_remove(path);
+        ARTICLE_CONTENT_CACHE.lock().await.cache_remove(path);
+    }
+
+    // Series entries are keyed per language
+    let languages = content_index().languages();
+    for series in &changes.series {
+        let mut cache = SERIES_CACHE.lock().await;
+        for lang in &languages {
+            cache.cache_remove(&format!("{}_{}", series, lang));
+        }
+    }
+
+    // Any article change can reorder or regroup the listings
+    if changes.rebuilt || !changes.articles.is_empty() || !changes.series.is_empty() {
+        LIST_FILES_CACHE.lock().await.cache_clear();
+        HOME_PAGE_CACHE.lock().await.cache_clear();
+        ALL_SERIES_CACHE.lock().await.cache_clear();
+        STANDALONE_CACHE.lock().await.cache_clear();
+        TAGS_CACHE.lock().await.cache_clear();
+        super::taxonomy::invalidate_taxonomy_pages().await;
+        super::archive::invalidate_archive().await;
+        super::feeds::invalidate_feeds().await;
+        super::sitemap::invalidate_sitemap().await;
+        super::authors::invalidate_author_pages().await;
+    }
+
+    if changes.about_me {
+        ABOUT_ME_CACHE.lock().await.cache_clear();
+    }
+
+    dioxus::logger::tracing::info!("Invalidated cached content for {:?}", changes);
+}
+
+/// Apply the configured lifetimes to every server-side cache
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespans(config: &CacheConfig) {
+    use cached::Cached;
+
+    let seconds = config.content_ttl;
+    LIST_FILES_CACHE.lock().await.cache_set_lifespan(seconds);
+    ARTICLE_CONTENT_CACHE.lock().await.cache_set_lifespan(seconds);
+    ARTICLE_CACHE.lock().await.cache_set_lifespan(seconds);
+    HOME_PAGE_CACHE.lock().await.cache_set_lifespan(seconds);
+    ALL_SERIES_CACHE.lock().await.cache_set_lifespan(seconds);
+    SERIES_CACHE.lock().await.cache_set_lifespan(seconds);
+    STANDALONE_CACHE.lock().await.cache_set_lifespan(seconds);
+    TAGS_CACHE.lock().await.cache_set_lifespan(seconds);
+    ABOUT_ME_CACHE.lock().await.cache_set_lifespan(seconds);
+    super::taxonomy::set_cache_lifespan(seconds).await;
+    super::archive::set_cache_lifespan(seconds).await;
+    super::feeds::set_cache_lifespan(seconds).await;
+    super::sitemap::set_cache_lifespan(seconds).await;
+    super::authors::set_cache_lifespan(seconds).await;
+    super::reading::set_cache_lifespan(seconds).await;
+    super::history::set_cache_lifespan(config.history_ttl).await;
+}
+
+/// List all available articles, one entry per translation group (server-side)
 #[server]
-#[cached::proc_macro::cached(time = 5, result = true, sync_writes = true)]
 pub async fn list_files() -> Result<Vec<ArticleMetadata>, ServerFnError> {
-    use futures::future::join_all;
-    use std::path::Path;
+    cached_listing().await
+}
 
-    let articles_dir = "articles";
-    let base_path = Path::new(articles_dir);
-
-    // Recursively collect all markdown files (using sync version to avoid async recursion complexity)
-    let file_paths = collect_markdown_files_sync(base_path)
-        .map_err(|e| ServerFnError::new(format!("Failed to read articles directory: {}", e)))?;
-
-    // Process all files in parallel
-    let futures = file_paths.into_iter().map(|path| async move {
-        let file_name = path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown")
-            .to_string();
-
-        // Extract title efficiently (only reads first few lines)
-        let title = extract_title_from_file(&path)
-            .await
-            .unwrap_or_else(|_| file_name.clone());
-
-        // Get relative path from articles directory
-        let relative_path = path
-            .strip_prefix(articles_dir)
-            .unwrap_or(&path)
-            .to_str()
-            .unwrap_or(&file_name)
-            .to_string();
-
-        ArticleMetadata {
-            name: file_name.clone(),
-            path: relative_path,
-            title,
-        }
-    });
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "LIST_FILES_CACHE", time = 21600, result = true, sync_writes = true)]
+async fn cached_listing() -> Result<Vec<ArticleMetadata>, ServerFnError> {
+    Ok(content_index().listing())
+}
 
-    let mut articles = join_all(futures).await;
+/// Validate a requested article path and check the index knows it
+///
+/// Refused paths answer 403 and unknown articles 404. Files the index skips,
+/// such as series summaries, count as unknown. When the index is empty
+/// (it failed to build) articles are only checked on disk.
+#[cfg(feature = "server")]
+pub(crate) fn resolve_article(path: &str) -> Result<ArticleId, ServerFnError> {
+    let id = ArticleId::parse(path).map_err(path_error)?;
+    id.resolve(std::path::Path::new(&articles_dir())).map_err(path_error)?;
 
-    // Sort by name
-    articles.sort_by(|a, b| a.name.cmp(&b.name));
+    let index = content_index();
+    if !index.is_empty() && index.article(id.as_str()).is_none() {
+        return Err(path_error(ArticlePathError::NotFound(path.to_string())));
+    }
+    Ok(id)
+}
 
-    Ok(articles)
+#[cfg(feature = "server")]
+fn path_error(e: ArticlePathError) -> ServerFnError {
+    ServerFnError::ServerError {
+        message: e.to_string(),
+        code: e.status_code(),
+        details: None,
+    }
 }
 
 /// Fetch article content from the filesystem (server-side)
 #[server]
+pub async fn fetch_article_content(path: String) -> Result<String, ServerFnError> {
+    cached_article_content(resolve_article(&path)?).await
+}
+
+#[cfg(feature = "server")]
 #[cached::proc_macro::cached(
-    time = 5,
+    name = "ARTICLE_CONTENT_CACHE",
+    time = 21600,
     result = true,
     sync_writes = true,
     key = "String",
-    convert = r#"{ path.clone() }"#
+    convert = r#"{ id.to_string() }"#
 )]
-pub async fn fetch_article_content(path: String) -> Result<String, ServerFnError> {
-    use tokio::fs;
-
-    // Sanitize the path to prevent directory traversal
-    let safe_path = path.replace("..", "");
-    let file_path = format!("articles/{}", safe_path);
+async fn cached_article_content(id: ArticleId) -> Result<String, ServerFnError> {
+    let file = id.resolve(std::path::Path::new(&articles_dir())).map_err(path_error)?;
 
-    let content = fs::read_to_string(&file_path)
+    let content = tokio::fs::read_to_string(&file)
         .await
         .map_err(|e| ServerFnError::new(format!("Failed to read article: {}", e)))?;
 
     Ok(content)
 }
 
-/// Fetch article with full metadata and processed content
-#[server]
-#[cached::proc_macro::cached(
-    time = 5,
-    result = true,
-    sync_writes = true,
-    key = "String",
-    convert = r#"{ path.clone() }"#
-)]
-pub async fn fetch_article_with_metadata(
+/// Read an article from disk and resolve its series, dates, translations and authors
+/// Used to build the content index; server functions answer from the index
+/// `last_commit` is the newest commit touching the file, from [`last_commit_dates`]
+///
+/// [`last_commit_dates`]: super::history::last_commit_dates
+#[cfg(feature = "server")]
+pub(crate) async fn load_article(
     path: String,
+    last_commit: Option<ArticleDate>,
 ) -> Result<ArticleWithMetadata, ServerFnError> {
+    use advanced_markdown_parser::excerpt;
     use std::path::Path;
     use tokio::fs;
 
-    // Sanitize the path to prevent directory traversal
-    let safe_path = path.replace("..", "");
-    let file_path = format!("articles/{}", safe_path);
+    let id = ArticleId::parse(&path).map_err(path_error)?;
+    let articles = articles_dir();
+    let file = id.resolve(Path::new(&articles)).map_err(path_error)?;
+    let safe_path = id.to_string();
+    let file_path = format!("{}/{}", articles, safe_path);
 
-    let raw_content = fs::read_to_string(&file_path)
+    let raw_content = fs::read_to_string(&file)
         .await
         .map_err(|e| ServerFnError::new(format!("Failed to read article: {}", e)))?;
 
     // Parse markdown with metadata using advanced_markdown_parser
     let parsed = parse_markdown_with_metadata(&raw_content);
+    for diagnostic in &parsed.diagnostics {
+        dioxus::logger::tracing::warn!("{}: {}", file_path, diagnostic);
+    }
     let mut toml_metadata = parsed.metadata;
     let content = parsed.content;
 
     // Extract primary series from folder structure
     let path_buf = Path::new(&file_path);
-    let primary_series = extract_series_from_path(path_buf, "articles");
+    let primary_series = extract_series_from_path(path_buf, &articles);
 
     // Set primary_series in metadata if detected from folder
     if let Some(ref mut metadata) = toml_metadata {
         if primary_series.is_some() {
             metadata.primary_series = primary_series;
         }
+
+        // Map tags, topics and category onto the site taxonomy
+        let diagnostics = content_index().taxonomy().normalize(metadata);
+        for diagnostic in &diagnostics {
+            dioxus::logger::tracing::warn!("{}: {}", file_path, diagnostic);
+        }
+
+        // Fall back to the last commit touching the file, then its modification time
+        if metadata.updated.is_none() {
+            metadata.updated = last_commit;
+        }
+        if metadata.updated.is_none() {
+            metadata.updated = fs::metadata(&file_path)
+                .await
+                .and_then(|m| m.modified())
+                .ok()
+                .map(ArticleDate::from);
+        }
     }
 
     // Extract title from content
@@ -239,26 +437,73 @@ pub async fn fetch_article_with_metadata(
 
     let name = safe_path.trim_end_matches(".md").to_string();
 
+    // Detect the language and find sibling translations
+    let (_, lang) = translation_base(path_buf);
+    let translations = find_translations(path_buf, &articles);
+
+    // Resolve author IDs against the registry
+    let authors = match toml_metadata {
+        Some(ref metadata) => {
+            let registry = super::authors::load_author_registry()
+                .await
+                .map_err(ServerFnError::new)?;
+            let (authors, unknown) = registry.resolve(metadata);
+            for id in unknown {
+                dioxus::logger::tracing::warn!("{}: unknown author ID '{}'", file_path, id);
+            }
+            authors
+        }
+        None => Vec::new(),
+    };
+
+    let excerpt = excerpt(&content, EXCERPT_LENGTH);
+
     Ok(ArticleWithMetadata {
         metadata: ArticleMetadata {
             name,
             path: safe_path,
             title,
+            lang,
+            translations,
         },
         toml_metadata,
         content,
+        authors,
+        excerpt,
     })
 }
 
-/// Fetch article content by name (without extension)
+/// Fetch article with full metadata and processed content
 #[server]
+pub async fn fetch_article_with_metadata(
+    path: String,
+) -> Result<ArticleWithMetadata, ServerFnError> {
+    cached_article(resolve_article(&path)?).await
+}
+
+#[cfg(feature = "server")]
 #[cached::proc_macro::cached(
-    time = 5,
+    name = "ARTICLE_CACHE",
+    time = 21600,
     result = true,
     sync_writes = true,
     key = "String",
-    convert = r#"{ name.clone() }"#
+    convert = r#"{ id.to_string() }"#
 )]
+async fn cached_article(id: ArticleId) -> Result<ArticleWithMetadata, ServerFnError> {
+    // Articles missing from the index (e.g. it failed to build) are read from disk
+    let indexed = content_index().article(id.as_str());
+    match indexed {
+        Some(article) => Ok(article),
+        None => {
+            let last_commit = super::history::last_commit_dates().await.get(id.as_str()).copied();
+            load_article(id.to_string(), last_commit).await
+        }
+    }
+}
+
+/// Fetch article content by name (without extension)
+#[server]
 pub async fn fetch_article_by_name(name: String) -> Result<String, ServerFnError> {
     fetch_article_content(format!("{}.md", name)).await
 }
@@ -279,7 +524,6 @@ pub struct HomePageDataWithMetadata {
 
 /// Fetch home page data (articles list + first article content) in a single call
 #[server]
-#[cached::proc_macro::cached(time = 5, result = true, sync_writes = true)]
 pub async fn fetch_home_page_data() -> Result<HomePageData, ServerFnError> {
     // Fetch articles list (cached)
     let articles = list_files().await?;
@@ -298,65 +542,38 @@ pub async fn fetch_home_page_data() -> Result<HomePageData, ServerFnError> {
 }
 
 /// Fetch home page data with metadata for all articles
+/// Articles are returned in `locale` where a translation exists, otherwise in the default language
 #[server]
-#[cached::proc_macro::cached(time = 5, result = true, sync_writes = true)]
-pub async fn fetch_home_page_data_with_metadata() -> Result<HomePageDataWithMetadata, ServerFnError>
-{
-    use futures::future::join_all;
-
-    dioxus::logger::tracing::info!("fetch_home_page_data_with_metadata: Starting");
-    let start = std::time::Instant::now();
-
-    // Fetch articles list (cached)
-    let list_start = std::time::Instant::now();
-    let articles = list_files().await?;
-    dioxus::logger::tracing::info!(
-        "fetch_home_page_data_with_metadata: Listed {} files in {:?}",
-        articles.len(),
-        list_start.elapsed()
-    );
-
-    // Fetch all articles with metadata in parallel
-    let fetch_start = std::time::Instant::now();
-    let futures = articles.iter().map(|article| {
-        let path = article.path.clone();
-        async move { fetch_article_with_metadata(path).await }
-    });
-
-    let results: Vec<Result<ArticleWithMetadata, ServerFnError>> = join_all(futures).await;
-    dioxus::logger::tracing::info!(
-        "fetch_home_page_data_with_metadata: Fetched all articles in {:?}",
-        fetch_start.elapsed()
-    );
-
-    // Collect successful results
-    let mut articles_with_metadata: Vec<ArticleWithMetadata> =
-        results.into_iter().filter_map(|r| r.ok()).collect();
+pub async fn fetch_home_page_data_with_metadata(
+    locale: Option<String>,
+) -> Result<HomePageDataWithMetadata, ServerFnError> {
+    let lang = content_index().resolve_locale(locale.as_deref());
+    cached_home_page(lang).await
+}
 
-    // Sort by date (most recent first) if date is available in metadata
-    articles_with_metadata.sort_by(|a, b| {
-        match (&a.toml_metadata, &b.toml_metadata) {
-            (Some(meta_a), Some(meta_b)) => {
-                // Sort by date descending (most recent first)
-                meta_b.date.cmp(&meta_a.date)
-            }
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => a.metadata.name.cmp(&b.metadata.name),
-        }
-    });
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "HOME_PAGE_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ lang.clone() }"#
+)]
+async fn cached_home_page(lang: String) -> Result<HomePageDataWithMetadata, ServerFnError> {
+    let mut articles = content_index().localized(Some(&lang));
 
-    let first_article = articles_with_metadata.first().cloned();
-    let recent_articles = articles_with_metadata;
+    // Sort by date (most recent first)
+    articles.sort_by(compare_by_date_desc);
 
     dioxus::logger::tracing::info!(
-        "fetch_home_page_data_with_metadata: Completed in {:?}",
-        start.elapsed()
+        "fetch_home_page_data_with_metadata: Returning {} articles",
+        articles.len()
     );
 
     Ok(HomePageDataWithMetadata {
-        first_article,
-        recent_articles,
+        first_article: articles.first().cloned(),
+        recent_articles: articles,
     })
 }
 
@@ -376,202 +593,55 @@ pub struct SeriesData {
     pub long_summary: Option<String>,
 }
 
-/// Fetch all series with their articles
+/// Fetch all series with their articles, localized to `locale` where possible
 #[server]
-#[cached::proc_macro::cached(time = 5, result = true, sync_writes = true)]
-pub async fn fetch_all_series() -> Result<Vec<SeriesData>, ServerFnError> {
-    use futures::future::join_all;
-    use std::collections::HashMap;
-
-    dioxus::logger::tracing::info!("fetch_all_series: Starting");
-
-    // Fetch articles list (cached)
-    let articles = list_files().await?;
-
-    // Fetch all articles with metadata in parallel
-    let futures = articles.iter().map(|article| {
-        let path = article.path.clone();
-        async move { fetch_article_with_metadata(path).await }
-    });
-
-    let results: Vec<Result<ArticleWithMetadata, ServerFnError>> = join_all(futures).await;
-
-    // Collect successful results and filter out summary.md files
-    let articles_with_metadata: Vec<ArticleWithMetadata> = results
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .filter(|article| {
-            dioxus::logger::tracing::info!("File names: {}", article.metadata.name);
-            !article.metadata.name.contains("summary")
-        })
-        .collect();
-
-    // Group articles by series
-    let mut series_map: HashMap<String, Vec<ArticleWithMetadata>> = HashMap::new();
-
-    for article in articles_with_metadata {
-        if let Some(ref metadata) = article.toml_metadata {
-            // Add to primary series from folder structure
-            if let Some(ref primary_series) = metadata.primary_series {
-                series_map
-                    .entry(primary_series.clone())
-                    .or_insert_with(Vec::new)
-                    .push(article.clone());
-            }
-
-            // Add to additional series from metadata
-            for series_name in &metadata.series {
-                series_map
-                    .entry(series_name.clone())
-                    .or_insert_with(Vec::new)
-                    .push(article.clone());
-            }
-        }
-    }
-
-    // Convert to SeriesData with summaries
-    let futures = series_map
-        .into_iter()
-        .map(|(name, mut articles)| async move {
-            // Sort articles by name within each series
-            articles.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
-
-            let total_articles = articles.len();
-
-            // Try to read summary.md from the series folder
-            let summary_path = format!("articles/{}/summary.md", name);
-            let (short_summary, long_summary) = match tokio::fs::read_to_string(&summary_path).await
-            {
-                Ok(content) => {
-                    // Parse TOML frontmatter and markdown content
-                    if content.starts_with("#####") {
-                        let parts: Vec<&str> = content.splitn(3, "#####").collect();
-                        if parts.len() >= 3 {
-                            let toml_str = parts[1].trim();
-                            let markdown_content = parts[2].trim();
-
-                            // Parse TOML metadata
-                            let metadata: Result<SeriesSummaryMetadata, _> =
-                                toml::from_str(toml_str);
-                            let short = metadata.ok().and_then(|m| m.short_summary);
-
-                            (short, Some(markdown_content.to_string()))
-                        } else {
-                            (None, Some(content))
-                        }
-                    } else {
-                        (None, Some(content))
-                    }
-                }
-                Err(_) => (None, None),
-            };
-
-            SeriesData {
-                name,
-                articles,
-                total_articles,
-                short_summary,
-                long_summary,
-            }
-        });
-
-    let mut series_list: Vec<SeriesData> = join_all(futures).await;
+pub async fn fetch_all_series(locale: Option<String>) -> Result<Vec<SeriesData>, ServerFnError> {
+    let lang = content_index().resolve_locale(locale.as_deref());
+    cached_all_series(lang).await
+}
 
-    // Sort series by name
-    series_list.sort_by(|a, b| a.name.cmp(&b.name));
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "ALL_SERIES_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ lang.clone() }"#
+)]
+async fn cached_all_series(lang: String) -> Result<Vec<SeriesData>, ServerFnError> {
+    let series_list = content_index().all_series(Some(&lang));
 
     dioxus::logger::tracing::info!("fetch_all_series: Found {} series", series_list.len());
 
     Ok(series_list)
 }
 
-/// Fetch a single series by name
+/// Fetch a single series by name, localized to `locale` where possible
 #[server]
+pub async fn fetch_series_by_name(
+    series_name: String,
+    locale: Option<String>,
+) -> Result<SeriesData, ServerFnError> {
+    let lang = content_index().resolve_locale(locale.as_deref());
+    cached_series(series_name, lang).await
+}
+
+#[cfg(feature = "server")]
 #[cached::proc_macro::cached(
-    time = 5,
+    name = "SERIES_CACHE",
+    time = 21600,
     result = true,
     sync_writes = true,
     key = "String",
-    convert = r#"{ series_name.clone() }"#
+    convert = r#"{ format!("{}_{}", series_name, lang) }"#
 )]
-pub async fn fetch_series_by_name(series_name: String) -> Result<SeriesData, ServerFnError> {
-    use futures::future::join_all;
-
+async fn cached_series(series_name: String, lang: String) -> Result<SeriesData, ServerFnError> {
     dioxus::logger::tracing::info!("fetch_series_by_name: Fetching series '{}'", series_name);
 
-    // Fetch articles list (cached)
-    let articles = list_files().await?;
-
-    // Fetch all articles with metadata in parallel
-    let futures = articles.iter().map(|article| {
-        let path = article.path.clone();
-        async move { fetch_article_with_metadata(path).await }
-    });
-
-    let results: Vec<Result<ArticleWithMetadata, ServerFnError>> = join_all(futures).await;
-
-    // Collect articles that belong to this series and filter out summary.md
-    let mut series_articles: Vec<ArticleWithMetadata> = results
-        .into_iter()
-        .filter_map(|r| r.ok())
-        .filter(|article| {
-            // Exclude summary.md files
-            if article.metadata.name == "summary" {
-                return false;
-            }
-
-            if let Some(ref metadata) = article.toml_metadata {
-                // Check if article belongs to this series
-                metadata.primary_series.as_ref() == Some(&series_name)
-                    || metadata.series.contains(&series_name)
-            } else {
-                false
-            }
-        })
-        .collect();
-
-    if series_articles.is_empty() {
-        return Err(ServerFnError::new("Series not found"));
-    }
-
-    // Sort articles by name
-    series_articles.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
-
-    let total_articles = series_articles.len();
-
-    // Read summary.md from the series folder
-    let summary_path = format!("articles/{}/summary.md", series_name);
-    let (short_summary, long_summary) = match tokio::fs::read_to_string(&summary_path).await {
-        Ok(content) => {
-            // Parse TOML frontmatter and markdown content
-            if content.starts_with("#####") {
-                let parts: Vec<&str> = content.splitn(3, "#####").collect();
-                if parts.len() >= 3 {
-                    let toml_str = parts[1].trim();
-                    let markdown_content = parts[2].trim();
-
-                    // Parse TOML metadata
-                    let metadata: Result<SeriesSummaryMetadata, _> = toml::from_str(toml_str);
-                    let short = metadata.ok().and_then(|m| m.short_summary);
-
-                    (short, Some(markdown_content.to_string()))
-                } else {
-                    (None, Some(content))
-                }
-            } else {
-                (None, Some(content))
-            }
-        }
-        Err(_) => (None, None),
-    };
-
-    Ok(SeriesData {
-        name: series_name,
-        articles: series_articles,
-        total_articles,
-        short_summary,
-        long_summary,
-    })
+    content_index()
+        .series(&series_name, Some(&lang))
+        .ok_or_else(|| ServerFnError::new("Series not found"))
 }
 
 /// Paginated articles response
@@ -584,105 +654,111 @@ pub struct PaginatedArticles {
     pub total_pages: usize,
 }
 
+impl PaginatedArticles {
+    /// Page size of the article and taxonomy listings
+    pub const PER_PAGE: usize = 12;
+
+    /// Largest page the server hands out
+    pub const MAX_PER_PAGE: usize = 50;
+
+    /// Page `page` (1-based) of `articles`, `per_page` at a time
+    /// `per_page` is clamped to `1..=MAX_PER_PAGE`; pages past the end are empty
+    pub fn paginate(articles: &[ArticleWithMetadata], page: usize, per_page: usize) -> Self {
+        let page = page.max(1);
+        let per_page = per_page.clamp(1, Self::MAX_PER_PAGE);
+        let total_count = articles.len();
+        let total_pages = total_count.div_ceil(per_page);
+
+        let start_idx = (page - 1).checked_mul(per_page).unwrap_or(usize::MAX).min(total_count);
+        let end_idx = start_idx.saturating_add(per_page).min(total_count);
+
+        PaginatedArticles {
+            articles: articles[start_idx..end_idx].to_vec(),
+            total_count,
+            page,
+            per_page,
+            total_pages,
+        }
+    }
+}
+
 /// Fetch standalone articles (not part of any series) with pagination
+/// Articles are localized to `locale` where possible
 #[server]
-#[cached::proc_macro::cached(
-    time = 5,
-    result = true,
-    sync_writes = true,
-    key = "String",
-    convert = r#"{ format!("{}_{}", page, per_page) }"#
-)]
 pub async fn fetch_standalone_articles(
     page: usize,
     per_page: usize,
+    locale: Option<String>,
 ) -> Result<PaginatedArticles, ServerFnError> {
-    use futures::future::join_all;
+    let lang = content_index().resolve_locale(locale.as_deref());
+    let articles = cached_standalone_articles(lang).await?;
+    let paginated = PaginatedArticles::paginate(&articles, page, per_page);
 
     dioxus::logger::tracing::info!(
-        "fetch_standalone_articles: page={}, per_page={}",
-        page,
-        per_page
+        "fetch_standalone_articles: Found {} total, returning {} for page {}",
+        paginated.total_count,
+        paginated.articles.len(),
+        paginated.page
     );
 
-    // Fetch articles list (cached)
-    let articles = list_files().await?;
-
-    // Fetch all articles with metadata in parallel
-    let futures = articles.iter().map(|article| {
-        let path = article.path.clone();
-        async move { fetch_article_with_metadata(path).await }
-    });
+    Ok(paginated)
+}
 
-    let results: Vec<Result<ArticleWithMetadata, ServerFnError>> = join_all(futures).await;
+/// Every standalone article in `lang`, newest first
+/// Keyed by language only, so page numbers from requests never reach the cache
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "STANDALONE_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ lang.clone() }"#
+)]
+async fn cached_standalone_articles(lang: String) -> Result<std::sync::Arc<Vec<ArticleWithMetadata>>, ServerFnError> {
 
-    // Collect successful results and filter for standalone articles
-    let mut standalone_articles: Vec<ArticleWithMetadata> = results
+    // Keep articles that are not part of any series
+    // Articles without metadata are considered standalone
+    let mut standalone_articles: Vec<ArticleWithMetadata> = content_index()
+        .localized(Some(&lang))
         .into_iter()
-        .filter_map(|r| r.ok())
         .filter(|article| {
-            // Filter out summary files
-            if article.metadata.name.contains("summary") {
-                return false;
-            }
-
-            // Keep articles that are not part of any series
-            if let Some(ref metadata) = article.toml_metadata {
-                metadata.primary_series.is_none() && metadata.series.is_empty()
-            } else {
-                // Articles without metadata are considered standalone
-                true
-            }
+            article
+                .toml_metadata
+                .as_ref()
+                .is_none_or(|metadata| metadata.primary_series.is_none() && metadata.series.is_empty())
         })
         .collect();
 
     // Sort by date (most recent first)
-    standalone_articles.sort_by(|a, b| {
-        match (&a.toml_metadata, &b.toml_metadata) {
-            (Some(meta_a), Some(meta_b)) => {
-                // Sort by date descending (most recent first)
-                meta_b.date.cmp(&meta_a.date)
-            }
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => a.metadata.name.cmp(&b.metadata.name),
-        }
-    });
-
-    let total_count = standalone_articles.len();
-    let total_pages = (total_count + per_page - 1) / per_page;
+    standalone_articles.sort_by(compare_by_date_desc);
 
-    // Calculate pagination
-    let start_idx = page.saturating_sub(1) * per_page;
-    let end_idx = (start_idx + per_page).min(total_count);
-
-    let paginated_articles = if start_idx < total_count {
-        standalone_articles[start_idx..end_idx].to_vec()
-    } else {
-        Vec::new()
-    };
+    Ok(std::sync::Arc::new(standalone_articles))
+}
 
-    dioxus::logger::tracing::info!(
-        "fetch_standalone_articles: Found {} total, returning {} for page {}",
-        total_count,
-        paginated_articles.len(),
-        page
-    );
+/// Fetch every tag with the number of articles carrying it
+#[server]
+pub async fn fetch_tags() -> Result<std::collections::BTreeMap<String, usize>, ServerFnError> {
+    cached_tag_counts().await
+}
 
-    Ok(PaginatedArticles {
-        articles: paginated_articles,
-        total_count,
-        page,
-        per_page,
-        total_pages,
-    })
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "TAGS_CACHE", time = 21600, result = true, sync_writes = true)]
+async fn cached_tag_counts() -> Result<std::collections::BTreeMap<String, usize>, ServerFnError> {
+    Ok(content_index().tag_counts())
 }
 
 /// Fetch about me content
 #[server]
-#[cached::proc_macro::cached(time = 300, result = true, sync_writes = true)]
 pub async fn fetch_about_me() -> Result<String, ServerFnError> {
-    match tokio::fs::read_to_string("aboutme.md").await {
+    cached_about_me().await
+}
+
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "ABOUT_ME_CACHE", time = 21600, result = true, sync_writes = true)]
+async fn cached_about_me() -> Result<String, ServerFnError> {
+    let path = site_config().content.about_me.clone();
+    match tokio::fs::read_to_string(&path).await {
         Ok(content) => Ok(content),
         Err(_) => Err(ServerFnError::new("About me file not found")),
     }
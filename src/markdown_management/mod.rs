@@ -1,12 +1,43 @@
 pub mod local;
+pub mod archive;
+pub mod authors;
 pub mod github;
+pub mod history;
+pub mod live_reload;
+pub mod lint;
+pub mod search;
+pub mod seo;
+pub mod site;
+pub mod static_data;
+pub mod offline;
+pub mod progress;
+pub mod reading;
+pub mod taxonomy;
 
 #[cfg(feature = "web")]
 pub mod github_cache;
 
+#[cfg(feature = "web")]
+pub mod search_cache;
+
+#[cfg(feature = "web")]
+pub mod article_cache;
+
+#[cfg(feature = "web")]
+pub mod progress_cache;
+
+#[cfg(feature = "server")]
+pub mod index;
+
 #[cfg(feature = "server")]
 pub mod watcher;
 
+#[cfg(feature = "server")]
+pub mod feeds;
+
+#[cfg(feature = "server")]
+pub mod sitemap;
+
 pub use local::{
     list_files,
     fetch_article_content,
@@ -17,6 +48,7 @@ pub use local::{
     fetch_series_by_name,
     fetch_standalone_articles,
     fetch_about_me,
+    fetch_tags,
     HomePageData,
     HomePageDataWithMetadata,
     ArticleMetadata,
@@ -24,11 +56,62 @@ pub use local::{
     SeriesInfo,
     SeriesData,
     PaginatedArticles,
+    Translation,
 };
 
 // Re-export types from advanced_markdown_parser
-pub use advanced_markdown_parser::{ArticleTomlMetadata, ArticleSeries, Reference};
+pub use advanced_markdown_parser::{
+    ArticleDate, ArticleTomlMetadata, ArticleSeries, Reference, SearchFilters, SearchIndex,
+    SearchResult, SnippetPart, LintFinding, LintReport, LintRule, Book, ReadingStatus, DEFAULT_LANGUAGE,
+};
+pub use archive::{fetch_archive, ArchiveEntry, ArchiveMonth, ArchiveYear};
 pub use github::{fetch_github_repos, GitHubRepo};
+pub use history::{fetch_article_history, ArticleRevision};
+pub use live_reload::{watch_content_changes, ContentChanged};
+pub use lint::lint_content;
+pub use offline::{
+    fetch_article_cached,
+    refresh_cached_article,
+    save_series_offline,
+    list_saved_articles,
+    remove_saved_article,
+    clear_saved_content,
+    SavedArticle,
+};
+pub use progress::{
+    record_progress,
+    fetch_series_progress,
+    next_unread,
+    progress_key,
+    ReadingProgress,
+};
+pub use reading::fetch_reading_list;
+pub use taxonomy::{
+    fetch_taxonomy_index,
+    fetch_articles_by_term,
+    TaxonomyIndex,
+    TaxonomyKind,
+};
+pub use site::{fetch_site_config, CacheConfig, ContentPaths, GitHubConfig, PublicSiteConfig, SiteConfig, SocialLink};
+pub use seo::{fetch_site_meta, fetch_article_meta, fetch_page_meta, PageMeta, SiteMeta};
+pub use search::{
+    search,
+    search_articles,
+    fetch_search_index,
+    fetch_search_index_version,
+};
+pub use authors::{
+    fetch_authors,
+    fetch_site_owner,
+    fetch_author_page,
+    Author,
+    AuthorLink,
+    AuthorPageData,
+};
 
 #[cfg(feature = "server")]
-pub use watcher::start_article_watcher;
+pub use index::build_content_index_blocking;
+#[cfg(feature = "server")]
+pub use site::{init_site_config, site_config_path};
+#[cfg(feature = "server")]
+pub use watcher::{WatchBackend, WatcherConfig, WatcherService};
@@ -0,0 +1,292 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::local::{fetch_article_with_metadata, ArticleWithMetadata};
+use super::static_data::exported;
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+use super::local::fetch_series_by_name;
+
+/// An article kept for offline reading
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedArticle {
+    pub path: String,
+    pub title: String,
+    pub series: Option<String>,
+    /// JavaScript timestamp of when it was stored
+    pub cached_at: f64,
+}
+
+// ============================================================================
+// WASM/Client-side implementation with IndexedDB
+// ============================================================================
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod store {
+    use anyhow::Result;
+    use netabase_store::databases::indexeddb_store::IndexedDBStore;
+    use wasm_bindgen::prelude::*;
+
+    use super::super::article_cache::{
+        ArticleCacheDefinition, CachedArticle, CachedArticlePrimaryKey, CachedArticleSavedSecondaryKey,
+        CachedArticleSecondaryKeys,
+    };
+    use super::super::local::ArticleWithMetadata;
+
+    const STORE_NAME: &str = "blogger_article_cache";
+
+    /// Articles cached while reading, beyond which the least recently
+    /// stored are evicted; explicit saves don't count and are never evicted
+    const MAX_CACHED_ARTICLES: usize = 50;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Date, js_name = now)]
+        pub(super) fn date_now() -> f64;
+    }
+
+    async fn open() -> Result<IndexedDBStore<ArticleCacheDefinition>> {
+        IndexedDBStore::<ArticleCacheDefinition>::new(STORE_NAME)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to open IndexedDB: {}", e))
+    }
+
+    pub(super) async fn get(path: &str) -> Result<Option<CachedArticle>> {
+        let store = open().await?;
+        store
+            .open_tree::<CachedArticle>()
+            .get(CachedArticlePrimaryKey(path.to_string()))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read from IndexedDB: {}", e))
+    }
+
+    /// Store `article`, keeping an earlier explicit save unless `series` saves it now
+    pub(super) async fn put(article: &ArticleWithMetadata, series: Option<String>) -> Result<()> {
+        let store = open().await?;
+        let tree = store.open_tree::<CachedArticle>();
+
+        let previous = tree
+            .get(CachedArticlePrimaryKey(article.metadata.path.clone()))
+            .await
+            .ok()
+            .flatten()
+            .filter(|cached| cached.saved);
+        let saved = series.is_some() || previous.is_some();
+        let series = series.or(previous.and_then(|cached| cached.series));
+
+        let cached = CachedArticle {
+            path: article.metadata.path.clone(),
+            title: article.metadata.title.clone(),
+            series,
+            saved,
+            article_json: serde_json::to_string(article)?,
+            cached_at: date_now(),
+        };
+        tree.put(cached)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to write to IndexedDB: {}", e))?;
+
+        if !saved {
+            evict(&store).await?;
+        }
+        Ok(())
+    }
+
+    /// Drop the oldest articles that were only cached while reading
+    async fn evict(store: &IndexedDBStore<ArticleCacheDefinition>) -> Result<()> {
+        let tree = store.open_tree::<CachedArticle>();
+        let mut cached = tree
+            .get_by_secondary_key(CachedArticleSecondaryKeys::Saved(CachedArticleSavedSecondaryKey(false)))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read from IndexedDB: {}", e))?;
+        if cached.len() <= MAX_CACHED_ARTICLES {
+            return Ok(());
+        }
+
+        // Newest first; everything past the cap goes
+        cached.sort_by(|a, b| b.cached_at.total_cmp(&a.cached_at));
+        for article in cached.into_iter().skip(MAX_CACHED_ARTICLES) {
+            tree.remove(CachedArticlePrimaryKey(article.path))
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to remove from IndexedDB: {}", e))?;
+        }
+        Ok(())
+    }
+
+    pub(super) async fn saved() -> Result<Vec<CachedArticle>> {
+        let store = open().await?;
+        store
+            .open_tree::<CachedArticle>()
+            .get_by_secondary_key(CachedArticleSecondaryKeys::Saved(CachedArticleSavedSecondaryKey(true)))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read from IndexedDB: {}", e))
+    }
+
+    pub(super) async fn remove(path: &str) -> Result<()> {
+        let store = open().await?;
+        store
+            .open_tree::<CachedArticle>()
+            .remove(CachedArticlePrimaryKey(path.to_string()))
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to remove from IndexedDB: {}", e))
+    }
+
+    pub(super) async fn clear() -> Result<()> {
+        let store = open().await?;
+        store
+            .open_tree::<CachedArticle>()
+            .clear()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to clear IndexedDB: {}", e))
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Fetch an article, answering from IndexedDB when it has been read or saved before
+/// Only the most recently read articles stay cached; saved ones are kept until removed
+/// Uses stale-while-revalidate: cached copies older than 5 minutes are returned
+/// straight away and refreshed in the background
+pub async fn fetch_article_cached(path: String) -> Result<ArticleWithMetadata> {
+    // Cache TTL: 5 minutes (in milliseconds)
+    const CACHE_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+
+    let cached = store::get(&path).await.unwrap_or_else(|e| {
+        dioxus::logger::tracing::warn!("Article cache unavailable: {}", e);
+        None
+    });
+    let cached_article = cached.as_ref().and_then(|cached| {
+        serde_json::from_str::<ArticleWithMetadata>(&cached.article_json)
+            .map(|article| (article, store::date_now() - cached.cached_at))
+            .ok()
+    });
+
+    if let Some((article, age)) = cached_article {
+        if age < CACHE_TTL_MS {
+            dioxus::logger::tracing::info!("Returning fresh cached article {} (age: {:.1}s)", path, age / 1000.0);
+            return Ok(article);
+        }
+
+        dioxus::logger::tracing::info!("Returning stale cached article {} (age: {:.1}s), refreshing in background", path, age / 1000.0);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = refresh_cached_article(path).await {
+                dioxus::logger::tracing::info!("Background article refresh failed: {}", e);
+            }
+        });
+
+        // Return stale data immediately (stale-while-revalidate)
+        return Ok(article);
+    }
+
+    refresh_cached_article(path).await
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Fetch an article from the server and update the cached copy
+/// Falls back to the cached copy when the server can't be reached
+pub async fn refresh_cached_article(path: String) -> Result<ArticleWithMetadata> {
+    let article_path = path.clone();
+    match exported!(fetch_article_with_metadata(article_path)).await {
+        Ok(article) => {
+            if let Err(e) = store::put(&article, None).await {
+                dioxus::logger::tracing::warn!("Failed to cache article {}: {}", path, e);
+            }
+            Ok(article)
+        }
+        Err(e) => {
+            let cached = store::get(&path)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|cached| serde_json::from_str(&cached.article_json).ok());
+            // The server's error stays reachable for its status code
+            cached.ok_or_else(|| anyhow::Error::new(e).context("Failed to fetch article"))
+        }
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Store every part of a series for offline reading
+/// Returns the number of articles saved
+pub async fn save_series_offline(series_name: String, locale: Option<String>) -> Result<usize> {
+    let name = series_name.clone();
+    let series = exported!(fetch_series_by_name(name, locale))
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch series: {}", e))?;
+
+    for article in &series.articles {
+        store::put(article, Some(series_name.clone())).await?;
+    }
+
+    dioxus::logger::tracing::info!("Saved {} articles from {} for offline reading", series.articles.len(), series_name);
+    Ok(series.articles.len())
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Articles the reader saved for offline reading, grouped by series
+pub async fn list_saved_articles() -> Result<Vec<SavedArticle>> {
+    let mut saved: Vec<SavedArticle> = store::saved()
+        .await?
+        .into_iter()
+        .map(|cached| SavedArticle {
+            path: cached.path,
+            title: cached.title,
+            series: cached.series,
+            cached_at: cached.cached_at,
+        })
+        .collect();
+    saved.sort_by(|a, b| a.series.cmp(&b.series).then_with(|| a.path.cmp(&b.path)));
+    Ok(saved)
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Forget one saved or cached article
+pub async fn remove_saved_article(path: String) -> Result<()> {
+    store::remove(&path).await
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Forget every saved and cached article
+pub async fn clear_saved_content() -> Result<()> {
+    store::clear().await
+}
+
+// ============================================================================
+// Fallback implementation (native, nothing is stored)
+// ============================================================================
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Fetch an article from the server
+pub async fn fetch_article_cached(path: String) -> Result<ArticleWithMetadata> {
+    refresh_cached_article(path).await
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Fetch an article from the server
+pub async fn refresh_cached_article(path: String) -> Result<ArticleWithMetadata> {
+    exported!(fetch_article_with_metadata(path))
+        .await
+        .map_err(|e| anyhow::Error::new(e).context("Failed to fetch article"))
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Offline reading is only available in the browser
+pub async fn save_series_offline(_series_name: String, _locale: Option<String>) -> Result<usize> {
+    Err(anyhow::anyhow!("Saving for offline reading is only available in the browser"))
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Nothing is saved outside the browser
+pub async fn list_saved_articles() -> Result<Vec<SavedArticle>> {
+    Ok(Vec::new())
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+pub async fn remove_saved_article(_path: String) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+pub async fn clear_saved_content() -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::local::{ArticleMetadata, ArticleWithMetadata, SeriesData};
+
+/// Scrolling this far through an article marks it as read
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+const COMPLETION_THRESHOLD: f64 = 0.95;
+
+/// How far the reader got through an article
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReadingProgress {
+    /// The article's [`progress_key`]
+    pub path: String,
+    pub series: Option<String>,
+    /// Last scroll position as a fraction of the article, 0.0 to 1.0
+    pub progress: f64,
+    /// Set once the reader reaches the end, and kept after scrolling back up
+    pub completed: bool,
+    /// JavaScript timestamp of the last update
+    pub updated_at: f64,
+}
+
+/// Key progress is stored under: the article path without its language
+/// suffix and extension, so reading any translation counts for all of them
+pub fn progress_key(metadata: &ArticleMetadata) -> String {
+    let path = metadata.path.trim_end_matches(".md");
+    path.strip_suffix(&format!(".{}", metadata.lang))
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// The first part of `series` the reader hasn't finished, if they have started it
+pub fn next_unread<'a>(
+    series: &'a SeriesData,
+    progress: &BTreeMap<String, ReadingProgress>,
+) -> Option<&'a ArticleWithMetadata> {
+    if progress.is_empty() {
+        return None;
+    }
+    series
+        .articles
+        .iter()
+        .find(|article| !progress.get(&progress_key(&article.metadata)).is_some_and(|p| p.completed))
+}
+
+// ============================================================================
+// WASM/Client-side implementation with IndexedDB
+// ============================================================================
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod store {
+    use anyhow::Result;
+    use netabase_store::databases::indexeddb_store::IndexedDBStore;
+    use wasm_bindgen::prelude::*;
+
+    use super::super::progress_cache::{
+        ProgressCacheDefinition, StoredProgress, StoredProgressPrimaryKey, StoredProgressSecondaryKeys,
+        StoredProgressSeriesSecondaryKey,
+    };
+
+    const STORE_NAME: &str = "blogger_reading_progress";
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Date, js_name = now)]
+        pub(super) fn date_now() -> f64;
+    }
+
+    async fn open() -> Result<IndexedDBStore<ProgressCacheDefinition>> {
+        IndexedDBStore::<ProgressCacheDefinition>::new(STORE_NAME)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to open IndexedDB: {}", e))
+    }
+
+    /// Primary key of `path`'s record for `series`
+    pub(super) fn record_id(series: &str, path: &str) -> String {
+        format!("{}\n{}", series, path)
+    }
+
+    pub(super) async fn get(id: &str) -> Result<Option<StoredProgress>> {
+        let store = open().await?;
+        store
+            .open_tree::<StoredProgress>()
+            .get(StoredProgressPrimaryKey(id.to_string()))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read from IndexedDB: {}", e))
+    }
+
+    pub(super) async fn put(progress: StoredProgress) -> Result<()> {
+        let store = open().await?;
+        store
+            .open_tree::<StoredProgress>()
+            .put(progress)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to write to IndexedDB: {}", e))
+    }
+
+    pub(super) async fn in_series(series: &str) -> Result<Vec<StoredProgress>> {
+        let store = open().await?;
+        store
+            .open_tree::<StoredProgress>()
+            .get_by_secondary_key(StoredProgressSecondaryKeys::Series(StoredProgressSeriesSecondaryKey(
+                series.to_string(),
+            )))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read from IndexedDB: {}", e))
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Remember how far the reader scrolled through an article, in every series
+/// it belongs to
+pub async fn record_progress(article: &ArticleWithMetadata, progress: f64) -> Result<()> {
+    use super::progress_cache::StoredProgress;
+
+    let path = progress_key(&article.metadata);
+    let mut series: Vec<String> = article
+        .toml_metadata
+        .as_ref()
+        .map(|metadata| metadata.series_names().into_iter().map(str::to_string).collect())
+        .unwrap_or_default();
+    if series.is_empty() {
+        series.push(String::new());
+    }
+
+    let progress = progress.clamp(0.0, 1.0);
+    let mut completed = progress >= COMPLETION_THRESHOLD;
+    for name in &series {
+        completed |= store::get(&store::record_id(name, &path)).await?.is_some_and(|previous| previous.completed);
+    }
+
+    let updated_at = store::date_now();
+    for name in series {
+        store::put(StoredProgress {
+            id: store::record_id(&name, &path),
+            path: path.clone(),
+            series: name,
+            progress,
+            completed,
+            updated_at,
+        })
+        .await?;
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Progress through each article of a series, keyed by [`progress_key`]
+pub async fn fetch_series_progress(series: String) -> Result<BTreeMap<String, ReadingProgress>> {
+    Ok(store::in_series(&series)
+        .await?
+        .into_iter()
+        .map(|stored| {
+            let progress = ReadingProgress {
+                path: stored.path.clone(),
+                series: Some(stored.series),
+                progress: stored.progress,
+                completed: stored.completed,
+                updated_at: stored.updated_at,
+            };
+            (stored.path, progress)
+        })
+        .collect())
+}
+
+// ============================================================================
+// Fallback implementation (native, nothing is recorded)
+// ============================================================================
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Progress is only recorded in the browser
+pub async fn record_progress(_article: &ArticleWithMetadata, _progress: f64) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Progress is only recorded in the browser
+pub async fn fetch_series_progress(_series: String) -> Result<BTreeMap<String, ReadingProgress>> {
+    Ok(BTreeMap::new())
+}
@@ -0,0 +1,28 @@
+use netabase_store::netabase_definition_module;
+
+#[netabase_definition_module(ProgressCacheDefinition, ProgressCacheKeys)]
+pub mod progress_cache_schema {
+    use netabase_store::{netabase, NetabaseModel};
+
+    /// How far the reader got through an article, stored in IndexedDB once
+    /// for every series it belongs to
+    #[derive(NetabaseModel, Clone, Debug, bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]
+    #[netabase(ProgressCacheDefinition)]
+    pub struct StoredProgress {
+        /// `series` and `path` joined by a newline
+        #[primary_key]
+        pub id: String,
+        /// Translation base of the article, shared by every language version
+        pub path: String,
+        /// Series this record counts towards, empty for standalone articles
+        #[secondary_key]
+        pub series: String,
+        /// Scroll position as a fraction of the article, 0.0 to 1.0
+        pub progress: f64,
+        pub completed: bool,
+        pub updated_at: f64, // JavaScript timestamp
+    }
+}
+
+// Re-export the generated types
+pub use progress_cache_schema::*;
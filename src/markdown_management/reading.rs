@@ -0,0 +1,44 @@
+use dioxus::prelude::*;
+
+use advanced_markdown_parser::{Book, ReadingStatus};
+#[cfg(feature = "server")]
+use advanced_markdown_parser::ReadingList;
+
+#[cfg(feature = "server")]
+use super::site::content_paths;
+
+/// Load `reading.toml` (a missing file is an empty list)
+/// Cached until the watcher reports a change to the file
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "READING_LIST_CACHE", time = 21600, result = true, sync_writes = true)]
+pub(crate) async fn load_reading_list() -> Result<ReadingList, String> {
+    let path = content_paths().reading;
+    match tokio::fs::read_to_string(&path).await {
+        Ok(content) => ReadingList::from_toml(&content).map_err(|e| format!("Invalid {}: {}", path, e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ReadingList::default()),
+        Err(e) => Err(format!("Failed to read {}: {}", path, e)),
+    }
+}
+
+/// Fetch the books on the reading list, optionally only those with `status`
+#[server]
+pub async fn fetch_reading_list(status: Option<ReadingStatus>) -> Result<Vec<Book>, ServerFnError> {
+    let list = load_reading_list().await.map_err(ServerFnError::new)?;
+    Ok(list.filter(status))
+}
+
+/// Drop the cached list after `reading.toml` changes
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_reading_list() {
+    use cached::Cached;
+
+    READING_LIST_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    READING_LIST_CACHE.lock().await.cache_set_lifespan(seconds);
+}
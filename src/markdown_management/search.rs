@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use advanced_markdown_parser::{SearchFilters, SearchIndex, SearchResult};
+
+#[cfg(feature = "server")]
+use super::index::content_index;
+
+/// Most results returned for one query
+#[cfg(any(feature = "server", all(feature = "web", target_arch = "wasm32")))]
+const MAX_RESULTS: usize = 30;
+
+/// Longest query accepted, in characters
+#[cfg(feature = "server")]
+const MAX_QUERY_LENGTH: usize = 200;
+
+/// Snippet text kept per article in the index shipped to the browser, in bytes
+#[cfg(feature = "server")]
+const COMPACT_TEXT_LENGTH: usize = 2000;
+
+/// Search article titles, summaries, headings, tags and bodies
+/// Results are ranked best first and carry a snippet with the matches highlighted
+#[server]
+pub async fn search(query: String, filters: SearchFilters) -> Result<Vec<SearchResult>, ServerFnError> {
+    if query.chars().count() > MAX_QUERY_LENGTH {
+        return Err(ServerFnError::new(format!(
+            "Search query is longer than {} characters",
+            MAX_QUERY_LENGTH
+        )));
+    }
+
+    Ok(content_index().search(&query, &filters, MAX_RESULTS))
+}
+
+/// Version of the current search index, so clients know when theirs is stale
+#[server]
+pub async fn fetch_search_index_version() -> Result<u64, ServerFnError> {
+    Ok(content_index().search_index().version)
+}
+
+/// Compact copy of the search index for searching in the browser
+#[server]
+pub async fn fetch_search_index() -> Result<SearchIndex, ServerFnError> {
+    Ok(content_index().search_index().compact(COMPACT_TEXT_LENGTH))
+}
+
+// ============================================================================
+// WASM/Client-side implementation with IndexedDB
+// ============================================================================
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod offline {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use anyhow::Result;
+    use advanced_markdown_parser::SearchIndex;
+    use netabase_store::databases::indexeddb_store::IndexedDBStore;
+    use wasm_bindgen::prelude::*;
+
+    use super::super::search_cache::{CachedSearchIndex, CachedSearchIndexPrimaryKey, SearchCacheDefinition};
+    use super::{fetch_search_index, fetch_search_index_version};
+    use super::super::static_data::exported;
+
+    const STORE_NAME: &str = "blogger_search_index";
+    const CACHE_KEY: &str = "search_index";
+
+    /// How long a loaded index is used before asking the server for its version again: 5 minutes
+    const VERSION_CHECK_INTERVAL_MS: f64 = 5.0 * 60.0 * 1000.0;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Date, js_name = now)]
+        fn date_now() -> f64;
+    }
+
+    thread_local! {
+        /// The index in use, with when its version was last checked
+        static LOADED: RefCell<Option<(Rc<SearchIndex>, f64)>> = const { RefCell::new(None) };
+    }
+
+    /// The search index, from memory, IndexedDB or the server
+    ///
+    /// The server is only asked for the full index when it reports a version
+    /// different from the stored one. When it can't be reached, the stored
+    /// index is used as is, so search keeps working offline.
+    pub(super) async fn local_search_index() -> Result<Rc<SearchIndex>> {
+        let now = date_now();
+        if let Some((index, checked_at)) = LOADED.with(|loaded| loaded.borrow().clone()) {
+            if now - checked_at < VERSION_CHECK_INTERVAL_MS {
+                return Ok(index);
+            }
+        }
+
+        let store = IndexedDBStore::<SearchCacheDefinition>::new(STORE_NAME)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to open IndexedDB: {}", e))?;
+        let tree = store.open_tree::<CachedSearchIndex>();
+
+        let stored = match LOADED.with(|loaded| loaded.borrow().clone()) {
+            Some((index, _)) => Some(index),
+            None => tree
+                .get(CachedSearchIndexPrimaryKey(CACHE_KEY.to_string()))
+                .await
+                .ok()
+                .flatten()
+                .and_then(|cached| decode(&cached.index))
+                .map(Rc::new),
+        };
+
+        let index = match exported!(fetch_search_index_version()).await {
+            Ok(version) if stored.as_ref().is_some_and(|index| index.version == version) => {
+                stored.expect("checked above")
+            }
+            Ok(version) => {
+                dioxus::logger::tracing::info!("Search index version {} available, downloading", version);
+                match exported!(fetch_search_index()).await {
+                    Ok(fresh) => {
+                        let cached = CachedSearchIndex {
+                            cache_key: CACHE_KEY.to_string(),
+                            version: fresh.version,
+                            index: encode(&fresh)?,
+                            cached_at: now,
+                        };
+                        if let Err(e) = tree.put(cached).await {
+                            dioxus::logger::tracing::warn!("Failed to store search index: {}", e);
+                        }
+                        Rc::new(fresh)
+                    }
+                    Err(e) => stored.ok_or_else(|| anyhow::anyhow!("Failed to fetch search index: {}", e))?,
+                }
+            }
+            Err(e) => {
+                dioxus::logger::tracing::info!("Search index version unavailable ({}), using stored index", e);
+                stored.ok_or_else(|| anyhow::anyhow!("No stored search index and the server is unreachable"))?
+            }
+        };
+
+        LOADED.with(|loaded| *loaded.borrow_mut() = Some((index.clone(), now)));
+        Ok(index)
+    }
+
+    fn encode(index: &SearchIndex) -> Result<Vec<u8>> {
+        bincode::serde::encode_to_vec(index, bincode::config::standard())
+            .map_err(|e| anyhow::anyhow!("Failed to encode search index: {}", e))
+    }
+
+    fn decode(bytes: &[u8]) -> Option<SearchIndex> {
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map(|(index, _)| index)
+            .ok()
+    }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+/// Search articles in the browser, against the index stored in IndexedDB
+/// Works offline once the index has been downloaded
+pub async fn search_articles(query: String, filters: SearchFilters) -> anyhow::Result<Vec<SearchResult>> {
+    let index = offline::local_search_index().await?;
+    Ok(index.search(&query, &filters, MAX_RESULTS))
+}
+
+// ============================================================================
+// Fallback implementation (native)
+// ============================================================================
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+/// Search articles by asking the server
+pub async fn search_articles(query: String, filters: SearchFilters) -> anyhow::Result<Vec<SearchResult>> {
+    search(query, filters)
+        .await
+        .map_err(|e| anyhow::anyhow!("Search failed: {}", e))
+}
@@ -0,0 +1,22 @@
+use netabase_store::netabase_definition_module;
+
+#[netabase_definition_module(SearchCacheDefinition, SearchCacheKeys)]
+pub mod search_cache_schema {
+    use netabase_store::{netabase, NetabaseModel};
+
+    /// Compact search index stored in IndexedDB for offline search
+    /// The index itself is bincode-encoded, since it comes from the parser crate
+    #[derive(NetabaseModel, Clone, Debug, bincode::Encode, bincode::Decode, serde::Serialize, serde::Deserialize)]
+    #[netabase(SearchCacheDefinition)]
+    pub struct CachedSearchIndex {
+        #[primary_key]
+        pub cache_key: String,
+        /// `SearchIndex::version` of the encoded index
+        pub version: u64,
+        pub index: Vec<u8>,
+        pub cached_at: f64, // JavaScript timestamp
+    }
+}
+
+// Re-export the generated types
+pub use search_cache_schema::*;
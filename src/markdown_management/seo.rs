@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use super::authors::load_author_registry;
+#[cfg(feature = "server")]
+use super::site::site_url;
+#[cfg(feature = "server")]
+use super::index::content_index;
+#[cfg(feature = "server")]
+use super::sitemap::encode_segment;
+
+/// Site-wide values every page's head tags are built from
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SiteMeta {
+    /// Public base URL, without a trailing slash
+    pub url: String,
+    pub name: String,
+    pub description: String,
+}
+
+/// Head tags for a single page
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PageMeta {
+    /// Full `<title>`, site name included
+    pub title: String,
+    pub description: String,
+    /// Absolute canonical URL
+    pub url: String,
+    /// Absolute URL of the share image
+    pub image: Option<String>,
+    /// OpenGraph type: "website" or "article"
+    pub kind: String,
+    pub site_name: String,
+    pub lang: Option<String>,
+    pub published: Option<String>,
+    pub modified: Option<String>,
+    pub authors: Vec<String>,
+    pub tags: Vec<String>,
+    /// schema.org JSON-LD, safe to embed in a `<script>` element
+    pub json_ld: Option<String>,
+    /// Keep search engines away (drafts and unlisted articles)
+    pub noindex: bool,
+}
+
+/// Serialize JSON-LD so it can't close the surrounding `<script>` element
+#[cfg(feature = "server")]
+fn embeddable_json(value: &serde_json::Value) -> String {
+    value.to_string().replace('<', "\\u003c")
+}
+
+/// Fetch the site's base URL, name and description
+#[server]
+pub async fn fetch_site_meta() -> Result<SiteMeta, ServerFnError> {
+    let registry = load_author_registry().await.map_err(ServerFnError::new)?;
+    let owner = registry.owner();
+
+    Ok(SiteMeta {
+        url: site_url(),
+        name: owner.map(|o| o.name.clone()).unwrap_or_else(|| "Blog".to_string()),
+        description: owner
+            .and_then(|o| o.bio.clone())
+            .unwrap_or_else(|| "Articles and series".to_string()),
+    })
+}
+
+/// Fetch head tags for an article: summary, share image, authors, dates,
+/// tags and a schema.org `BlogPosting`
+#[server]
+pub async fn fetch_article_meta(path: String) -> Result<PageMeta, ServerFnError> {
+    let site = fetch_site_meta().await?;
+    let article = content_index()
+        .article(&path)
+        .ok_or_else(|| ServerFnError::new(format!("Article not found: {}", path)))?;
+    let metadata = article.toml_metadata.clone().unwrap_or_default();
+
+    let encoded: Vec<String> = path.trim_end_matches(".md").split('/').map(encode_segment).collect();
+    let url = format!("{}/article/{}", site.url, encoded.join("/"));
+    let description = metadata
+        .summary
+        .clone()
+        .unwrap_or_else(|| article.excerpt.clone());
+    let image = metadata.thumbnail.as_ref().map(|thumbnail| {
+        if thumbnail.starts_with("http://") || thumbnail.starts_with("https://") {
+            thumbnail.clone()
+        } else {
+            format!("{}/{}", site.url, thumbnail.trim_start_matches('/'))
+        }
+    });
+
+    let mut authors: Vec<String> = article.authors.iter().map(|a| a.name.clone()).collect();
+    if authors.is_empty() {
+        authors.extend(metadata.author.clone());
+    }
+    let published = metadata.date.map(|d| d.to_rfc3339());
+    let modified = metadata.updated.max(metadata.date).map(|d| d.to_rfc3339());
+
+    let mut json_ld = serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "BlogPosting",
+        "headline": article.metadata.title,
+        "description": description,
+        "url": url,
+        "mainEntityOfPage": { "@type": "WebPage", "@id": url },
+        "inLanguage": article.metadata.lang,
+        "author": authors
+            .iter()
+            .map(|name| serde_json::json!({ "@type": "Person", "name": name }))
+            .collect::<Vec<_>>(),
+        "publisher": { "@type": "Person", "name": site.name },
+    });
+    if let Some(ref published) = published {
+        json_ld["datePublished"] = published.clone().into();
+    }
+    if let Some(ref modified) = modified {
+        json_ld["dateModified"] = modified.clone().into();
+    }
+    if let Some(ref image) = image {
+        json_ld["image"] = image.clone().into();
+    }
+    if !metadata.tags.is_empty() {
+        json_ld["keywords"] = metadata.tags.join(", ").into();
+    }
+
+    Ok(PageMeta {
+        title: format!("{} · {}", article.metadata.title, site.name),
+        description,
+        url,
+        image,
+        kind: "article".to_string(),
+        site_name: site.name,
+        lang: Some(article.metadata.lang),
+        published,
+        modified,
+        authors,
+        noindex: !metadata.is_listed(),
+        tags: metadata.tags,
+        json_ld: Some(embeddable_json(&json_ld)),
+    })
+}
+
+/// Head tags for a page that isn't an article
+///
+/// `title` is the page's own title, or `None` for the home page.
+#[server]
+pub async fn fetch_page_meta(
+    path: String,
+    title: Option<String>,
+    description: Option<String>,
+) -> Result<PageMeta, ServerFnError> {
+    let site = fetch_site_meta().await?;
+    let url = format!("{}{}", site.url, path);
+    let description = description.unwrap_or_else(|| site.description.clone());
+
+    let json_ld = match title {
+        None => serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "WebSite",
+            "name": site.name,
+            "description": description,
+            "url": url,
+            "potentialAction": {
+                "@type": "SearchAction",
+                "target": format!("{}/search?query={{query}}", site.url),
+                "query-input": "required name=query",
+            },
+        }),
+        Some(ref title) => serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "WebPage",
+            "name": title,
+            "description": description,
+            "url": url,
+            "isPartOf": { "@type": "WebSite", "name": site.name, "url": site.url },
+        }),
+    };
+
+    Ok(PageMeta {
+        title: match title {
+            Some(title) => format!("{} · {}", title, site.name),
+            None => site.name.clone(),
+        },
+        description,
+        url,
+        kind: "website".to_string(),
+        site_name: site.name,
+        json_ld: Some(embeddable_json(&json_ld)),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,270 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::github::GitHubAccountType;
+use advanced_markdown_parser::DEFAULT_LANGUAGE;
+#[cfg(feature = "server")]
+use advanced_markdown_parser::is_language_tag;
+
+#[cfg(feature = "server")]
+use std::path::PathBuf;
+#[cfg(feature = "server")]
+use std::sync::{LazyLock, RwLock, RwLockReadGuard};
+
+/// Config file read when `SITE_CONFIG` isn't set
+#[cfg(feature = "server")]
+const SITE_CONFIG_FILE: &str = "site.toml";
+
+/// Where the site's content lives, relative to the working directory
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContentPaths {
+    pub articles: String,
+    pub about_me: String,
+    /// Author registry
+    pub authors: String,
+    /// Tag aliases and topics
+    pub taxonomy: String,
+    /// The reading list
+    pub reading: String,
+    /// Crawler rules served as `/robots.txt`
+    pub robots: String,
+    /// Where images with a root-relative path (`/diagram.png`) are served from
+    pub public: String,
+}
+
+impl Default for ContentPaths {
+    fn default() -> Self {
+        ContentPaths {
+            articles: "articles".to_string(),
+            about_me: "aboutme.md".to_string(),
+            authors: "authors.toml".to_string(),
+            taxonomy: "taxonomy.toml".to_string(),
+            reading: "reading.toml".to_string(),
+            robots: "robots.txt".to_string(),
+            public: "public".to_string(),
+        }
+    }
+}
+
+/// The GitHub account whose repositories are listed on the home page
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GitHubConfig {
+    pub account: String,
+    #[serde(default)]
+    pub account_type: GitHubAccountType,
+    /// Target of "View all repositories" (defaults to the account's page)
+    #[serde(default)]
+    pub profile: Option<String>,
+}
+
+impl GitHubConfig {
+    pub fn profile_url(&self) -> String {
+        self.profile
+            .clone()
+            .unwrap_or_else(|| format!("https://github.com/{}", self.account))
+    }
+}
+
+/// A link in the home page's "Connect" box
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SocialLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// How long server-side caches keep results, in seconds
+///
+/// Caches are also cleared whenever the watcher sees the content change.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Articles, listings, series, tags, feeds and the sitemap
+    pub content_ttl: u64,
+    /// Git history of an article
+    pub history_ttl: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            content_ttl: 21600,
+            history_ttl: 300,
+        }
+    }
+}
+
+/// Site-wide settings from `site.toml`; every field has a default
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SiteConfig {
+    /// Public base URL; `SITE_URL` takes precedence
+    pub url: Option<String>,
+    pub content: ContentPaths,
+    /// Languages articles are written in; only these count as translation
+    /// suffixes (`intro.fr.md`), so `notes.api.md` stays its own article
+    pub languages: Vec<String>,
+    pub github: Option<GitHubConfig>,
+    /// Contact address, without `mailto:`
+    pub email: Option<String>,
+    pub social: Vec<SocialLink>,
+    /// Footer text; `{owner}` is replaced with the site owner's name
+    pub copyright: String,
+    pub cache: CacheConfig,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        SiteConfig {
+            url: None,
+            content: ContentPaths::default(),
+            languages: vec![DEFAULT_LANGUAGE.to_string()],
+            github: None,
+            email: None,
+            social: Vec::new(),
+            copyright: "© {owner}. Built with Rust & Dioxus.".to_string(),
+            cache: CacheConfig::default(),
+        }
+    }
+}
+
+/// The settings pages need; content paths and cache lifetimes stay on the server
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PublicSiteConfig {
+    /// Public base URL, as [`site_url`] resolves it
+    pub url: String,
+    pub languages: Vec<String>,
+    pub github: Option<GitHubConfig>,
+    pub email: Option<String>,
+    pub social: Vec<SocialLink>,
+    pub copyright: String,
+}
+
+impl PublicSiteConfig {
+    /// The footer text for `owner`
+    pub fn copyright_for(&self, owner: &str) -> String {
+        self.copyright.replace("{owner}", owner)
+    }
+}
+
+/// Fetch the public part of the site configuration
+#[server]
+pub async fn fetch_site_config() -> Result<PublicSiteConfig, ServerFnError> {
+    let url = site_url();
+    let config = site_config();
+    Ok(PublicSiteConfig {
+        url,
+        languages: config.languages.clone(),
+        github: config.github.clone(),
+        email: config.email.clone(),
+        social: config.social.clone(),
+        copyright: config.copyright.clone(),
+    })
+}
+
+#[cfg(feature = "server")]
+static SITE_CONFIG: LazyLock<RwLock<SiteConfig>> = LazyLock::new(Default::default);
+
+/// The loaded configuration; don't hold the guard across an `.await`
+#[cfg(feature = "server")]
+pub(crate) fn site_config() -> RwLockReadGuard<'static, SiteConfig> {
+    SITE_CONFIG.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Directory the articles are read from
+#[cfg(feature = "server")]
+pub(crate) fn articles_dir() -> String {
+    site_config().content.articles.clone()
+}
+
+/// Where every content file is read from
+#[cfg(feature = "server")]
+pub(crate) fn content_paths() -> ContentPaths {
+    site_config().content.clone()
+}
+
+/// Public base URL of the site, without a trailing slash: `SITE_URL`, then
+/// `url` from `site.toml`, then the local development address
+#[cfg(feature = "server")]
+pub(crate) fn site_url() -> String {
+    std::env::var("SITE_URL")
+        .ok()
+        .or_else(|| site_config().url.clone())
+        .unwrap_or_else(|| "http://localhost:8080".to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// `SITE_CONFIG`, or `site.toml` in the working directory
+#[cfg(feature = "server")]
+pub fn site_config_path() -> PathBuf {
+    std::env::var_os("SITE_CONFIG")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(SITE_CONFIG_FILE))
+}
+
+/// Read the config file (a missing file gives the defaults)
+#[cfg(feature = "server")]
+fn read_site_config() -> Result<SiteConfig, String> {
+    let path = site_config_path();
+    let config: SiteConfig = match std::fs::read_to_string(&path) {
+        Ok(content) => toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(SiteConfig::default()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+
+    if let Some(lang) = config.languages.iter().find(|lang| !is_language_tag(lang)) {
+        return Err(format!("{}: \"{}\" is not a language code", path.display(), lang));
+    }
+    Ok(config)
+}
+
+/// Languages that count as translation suffixes
+#[cfg(feature = "server")]
+pub(crate) fn site_languages() -> Vec<String> {
+    site_config().languages.clone()
+}
+
+/// Load the configuration before anything reads content; an invalid file
+/// is reported and the defaults are used
+#[cfg(feature = "server")]
+pub fn init_site_config() -> std::io::Result<()> {
+    let config = read_site_config().unwrap_or_else(|e| {
+        dioxus::logger::tracing::error!("{}; using default site settings", e);
+        SiteConfig::default()
+    });
+    let cache = config.cache.clone();
+    *SITE_CONFIG.write().unwrap_or_else(|e| e.into_inner()) = config;
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(super::local::set_cache_lifespans(&cache));
+    Ok(())
+}
+
+/// Re-read the configuration after the file changed, keeping the previous
+/// settings if the new file is invalid
+///
+/// Returns whether the content paths changed.
+#[cfg(feature = "server")]
+pub(crate) async fn reload_site_config() -> bool {
+    let config = match read_site_config() {
+        Ok(config) => config,
+        Err(e) => {
+            dioxus::logger::tracing::error!("{}; keeping the previous site settings", e);
+            return false;
+        }
+    };
+
+    let cache = config.cache.clone();
+    let content_changed = {
+        let mut current = SITE_CONFIG.write().unwrap_or_else(|e| e.into_inner());
+        let changed = current.content != config.content;
+        *current = config;
+        changed
+    };
+
+    super::local::set_cache_lifespans(&cache).await;
+    content_changed
+}
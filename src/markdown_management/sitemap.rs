@@ -0,0 +1,129 @@
+//! `/sitemap.xml` and `/robots.txt` for search engines
+
+use std::collections::BTreeMap;
+
+use advanced_markdown_parser::{render_robots, render_sitemap, ArticleDate, SitemapEntry};
+pub(crate) use advanced_markdown_parser::encode_segment;
+use dioxus::server::axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+
+use super::site::{content_paths, site_url};
+use super::index::content_index;
+
+/// Pages that aren't built from content, in sitemap order
+const STATIC_PAGES: &[&str] = &["/", "/articles", "/series", "/tags", "/archive", "/about", "/reading", "/demos"];
+
+/// Sitemap and robots routes to merge into the app's router
+pub fn router() -> Router {
+    Router::new()
+        .route("/sitemap.xml", get(serve_sitemap))
+        .route("/robots.txt", get(serve_robots))
+}
+
+async fn serve_sitemap() -> Response {
+    match cached_sitemap().await {
+        Ok(xml) => ([(header::CONTENT_TYPE, "application/xml; charset=utf-8")], xml).into_response(),
+        Err(e) => {
+            dioxus::logger::tracing::error!("Failed to build sitemap: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to build sitemap").into_response()
+        }
+    }
+}
+
+/// Read on every request so edits apply without a restart; a permissive
+/// default is used when the file is missing
+async fn serve_robots() -> Response {
+    let path = content_paths().robots;
+    let rules = match tokio::fs::read_to_string(&path).await {
+        Ok(rules) => Some(rules),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            dioxus::logger::tracing::warn!("Failed to read {}: {}", path, e);
+            None
+        }
+    };
+
+    let robots = render_robots(rules.as_deref(), &format!("{}/sitemap.xml", site_url()));
+    ([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], robots).into_response()
+}
+
+/// Clear the cached sitemap after content changes
+pub(crate) async fn invalidate_sitemap() {
+    use cached::Cached;
+
+    SITEMAP_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    SITEMAP_CACHE.lock().await.cache_set_lifespan(seconds);
+}
+
+/// Every listed article, plus the series, tag, topic and category pages
+/// they appear on, each dated by its most recently changed article
+#[cached::proc_macro::cached(name = "SITEMAP_CACHE", time = 21600, result = true, sync_writes = true)]
+async fn cached_sitemap() -> Result<String, String> {
+    let site = site_url();
+    let mut articles = Vec::new();
+    let mut newest: Option<ArticleDate> = None;
+    // Keyed by URL prefix, then term
+    let mut terms: BTreeMap<&str, BTreeMap<String, Option<ArticleDate>>> = BTreeMap::new();
+
+    for article in content_index().all_articles() {
+        // Drafts and unlisted articles are reachable only by direct link
+        let Some(metadata) = article.toml_metadata.as_ref().filter(|m| m.is_listed()) else {
+            continue;
+        };
+
+        // `updated` falls back to the file's mtime when not set
+        let lastmod = metadata.updated.max(metadata.date);
+        newest = newest.max(lastmod);
+
+        let path = article.metadata.path.trim_end_matches(".md");
+        articles.push(SitemapEntry {
+            loc: format!(
+                "{}/article/{}",
+                site,
+                path.split('/').map(encode_segment).collect::<Vec<_>>().join("/")
+            ),
+            lastmod,
+        });
+
+        let series = metadata.primary_series.iter().chain(metadata.series.iter());
+        let groups = [
+            ("series", series.collect::<Vec<_>>()),
+            ("tags", metadata.tags.iter().collect()),
+            ("topics", metadata.topics.iter().collect()),
+            ("category", metadata.category.iter().collect()),
+        ];
+        for (prefix, values) in groups {
+            for value in values {
+                let date = terms.entry(prefix).or_default().entry(value.clone()).or_default();
+                *date = (*date).max(lastmod);
+            }
+        }
+    }
+
+    let mut entries: Vec<SitemapEntry> = STATIC_PAGES
+        .iter()
+        .map(|page| SitemapEntry {
+            loc: format!("{}{}", site, page),
+            lastmod: if *page == "/" { newest } else { None },
+        })
+        .collect();
+    entries.extend(articles);
+    for (prefix, values) in terms {
+        entries.extend(values.into_iter().map(|(term, lastmod)| SitemapEntry {
+            loc: format!("{}/{}/{}", site, prefix, encode_segment(&term)),
+            lastmod,
+        }));
+    }
+
+    Ok(render_sitemap(&entries))
+}
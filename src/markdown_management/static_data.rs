@@ -0,0 +1,171 @@
+//! Server-function results saved by `blogger export`
+//!
+//! A static copy of the site has no server to call, so every result its pages
+//! need is written to `/data/<function>/<hash of the arguments>.json`. Pages
+//! load their data through [`exported!`]: on a static copy the browser reads
+//! those files, everywhere else the server function is called as usual.
+
+use dioxus::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Folder under the export root that holds the saved results
+pub const DATA_DIR: &str = "data";
+
+/// Call a server function, or read its saved result on a static copy of the site
+///
+/// `exported!(fetch_archive(year, month, locale))` takes plain identifiers so
+/// the arguments can be hashed before they are moved into the call.
+#[macro_export]
+macro_rules! exported {
+    ($function:ident($($arg:ident),* $(,)?)) => {{
+        let data_path = $crate::markdown_management::static_data::data_path(stringify!($function), &($(&$arg,)*));
+        $crate::markdown_management::static_data::load(data_path, $function($($arg),*))
+    }};
+}
+
+pub use crate::exported;
+
+/// URL path of the saved result of `function(args)`
+pub fn data_path(function: &str, args: &impl Serialize) -> String {
+    let args = serde_json::to_string(args).unwrap_or_default();
+    let hash = args.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("/{}/{}/{:016x}.json", DATA_DIR, function, hash)
+}
+
+/// Run `call`, or fetch `data_path` instead on a static copy of the site
+///
+/// While exporting, successful results are also written under `data_path`.
+pub async fn load<T>(
+    data_path: String,
+    call: impl Future<Output = Result<T, ServerFnError>>,
+) -> Result<T, ServerFnError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    if exported_languages().is_some() {
+        return client::fetch(&data_path).await;
+    }
+
+    let result = call.await;
+    #[cfg(feature = "server")]
+    if let Ok(ref value) = result {
+        recorder::record(&data_path, value);
+    }
+    #[cfg(not(feature = "server"))]
+    let _ = data_path;
+    result
+}
+
+/// Languages of a static copy of the site, or `None` when a server is running it
+///
+/// The exporter defines `bloggerExport()` in every page it writes.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+pub fn exported_languages() -> Option<Vec<String>> {
+    use std::sync::OnceLock;
+
+    static LANGUAGES: OnceLock<Option<Vec<String>>> = OnceLock::new();
+    LANGUAGES
+        .get_or_init(|| {
+            let export = client::blogger_export().ok()?;
+            serde_json::from_str::<ExportInfo>(&export).ok().map(|info| info.languages)
+        })
+        .clone()
+}
+
+/// A server is always running outside the browser
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+pub fn exported_languages() -> Option<Vec<String>> {
+    None
+}
+
+/// What `bloggerExport()` returns, as JSON
+#[derive(Debug, Serialize, serde::Deserialize)]
+pub struct ExportInfo {
+    pub languages: Vec<String>,
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+mod client {
+    use dioxus::prelude::*;
+    use serde::de::DeserializeOwned;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// Defined by the exporter in every page of a static copy
+        #[wasm_bindgen(catch, js_name = bloggerExport)]
+        pub(super) fn blogger_export() -> Result<String, JsValue>;
+    }
+
+    pub(super) async fn fetch<T: DeserializeOwned>(data_path: &str) -> Result<T, ServerFnError> {
+        let response = gloo_net::http::Request::get(data_path)
+            .send()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Failed to load {}: {}", data_path, e)))?;
+
+        if !response.ok() {
+            return Err(ServerFnError::ServerError {
+                message: format!("{} is not part of this export", data_path),
+                code: response.status(),
+                details: None,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Invalid {}: {}", data_path, e)))
+    }
+}
+
+/// Where results are written while exporting: `EXPORT_DATA_DIR` for the
+/// temporary server, or the folder passed to [`record_into`] by the exporter
+#[cfg(feature = "server")]
+mod recorder {
+    use std::path::PathBuf;
+    use std::sync::OnceLock;
+
+    use serde::Serialize;
+
+    static RECORD_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+    pub(super) fn record_dir() -> Option<&'static PathBuf> {
+        RECORD_DIR
+            .get_or_init(|| std::env::var_os("EXPORT_DATA_DIR").map(PathBuf::from))
+            .as_ref()
+    }
+
+    pub(super) fn record(data_path: &str, value: &impl Serialize) {
+        let Some(root) = record_dir() else {
+            return;
+        };
+
+        let path = root.join(data_path.trim_start_matches('/'));
+        let written = serde_json::to_vec(value)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(&path, json)
+            });
+        if let Err(e) = written {
+            dioxus::logger::tracing::error!("Failed to save {}: {}", path.display(), e);
+        }
+    }
+
+    pub(super) fn set(root: PathBuf) -> bool {
+        RECORD_DIR.set(Some(root)).is_ok()
+    }
+}
+
+/// Write every result loaded through [`exported!`] from now on under `root`
+#[cfg(feature = "server")]
+pub fn record_into(root: std::path::PathBuf) -> anyhow::Result<()> {
+    if !recorder::set(root) {
+        anyhow::bail!("Export data is already being recorded elsewhere");
+    }
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::local::PaginatedArticles;
+
+#[cfg(feature = "server")]
+use super::index::content_index;
+#[cfg(feature = "server")]
+use super::local::{compare_by_date_desc, ArticleWithMetadata};
+
+/// The ways articles are classified in their front matter
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TaxonomyKind {
+    Tag,
+    Topic,
+    Category,
+}
+
+impl TaxonomyKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            TaxonomyKind::Tag => "Tag",
+            TaxonomyKind::Topic => "Topic",
+            TaxonomyKind::Category => "Category",
+        }
+    }
+
+    pub fn plural(self) -> &'static str {
+        match self {
+            TaxonomyKind::Tag => "Tags",
+            TaxonomyKind::Topic => "Topics",
+            TaxonomyKind::Category => "Categories",
+        }
+    }
+}
+
+/// Every tag, topic and category with its number of articles
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxonomyIndex {
+    pub tags: BTreeMap<String, usize>,
+    pub topics: BTreeMap<String, usize>,
+    pub categories: BTreeMap<String, usize>,
+    /// Descriptions of tags and categories from `taxonomy.toml`
+    pub descriptions: BTreeMap<String, String>,
+}
+
+/// Clear the cached taxonomy pages after content changes (server-side)
+#[cfg(feature = "server")]
+pub(crate) async fn invalidate_taxonomy_pages() {
+    use cached::Cached;
+
+    TAXONOMY_INDEX_CACHE.lock().await.cache_clear();
+    TAXONOMY_ARTICLES_CACHE.lock().await.cache_clear();
+}
+
+/// Apply the configured cache lifetime (server-side)
+#[cfg(feature = "server")]
+pub(crate) async fn set_cache_lifespan(seconds: u64) {
+    use cached::Cached;
+
+    TAXONOMY_INDEX_CACHE.lock().await.cache_set_lifespan(seconds);
+    TAXONOMY_ARTICLES_CACHE.lock().await.cache_set_lifespan(seconds);
+}
+
+/// Fetch every tag, topic and category with its number of articles
+#[server]
+pub async fn fetch_taxonomy_index() -> Result<TaxonomyIndex, ServerFnError> {
+    cached_taxonomy_index().await
+}
+
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(name = "TAXONOMY_INDEX_CACHE", time = 21600, result = true, sync_writes = true)]
+async fn cached_taxonomy_index() -> Result<TaxonomyIndex, ServerFnError> {
+    let index = content_index();
+    let taxonomy = index.taxonomy();
+
+    let tag_descriptions = taxonomy
+        .tags
+        .iter()
+        .filter_map(|(name, tag)| Some((name.clone(), tag.description.clone()?)));
+    let category_descriptions = taxonomy
+        .categories
+        .iter()
+        .filter_map(|(name, category)| Some((name.clone(), category.description.clone()?)));
+
+    Ok(TaxonomyIndex {
+        tags: index.term_counts(TaxonomyKind::Tag),
+        topics: index.term_counts(TaxonomyKind::Topic),
+        categories: index.term_counts(TaxonomyKind::Category),
+        descriptions: tag_descriptions.chain(category_descriptions).collect(),
+    })
+}
+
+/// Fetch the articles filed under a tag, topic or category, newest first, with pagination
+/// Articles are localized to `locale` where possible
+#[server]
+pub async fn fetch_articles_by_term(
+    kind: TaxonomyKind,
+    term: String,
+    page: usize,
+    per_page: usize,
+    locale: Option<String>,
+) -> Result<PaginatedArticles, ServerFnError> {
+    // Resolve before caching so arbitrary terms can't grow the cache
+    let lang = {
+        let index = content_index();
+        if !index.has_term(kind, &term) {
+            return Err(ServerFnError::ServerError {
+                message: format!("{} not found: {}", kind.label(), term),
+                code: 404,
+                details: None,
+            });
+        }
+        index.resolve_locale(locale.as_deref())
+    };
+
+    let articles = cached_articles_by_term(kind, term, lang).await?;
+    Ok(PaginatedArticles::paginate(&articles, page, per_page))
+}
+
+/// Every article filed under `term` in `lang`, newest first
+#[cfg(feature = "server")]
+#[cached::proc_macro::cached(
+    name = "TAXONOMY_ARTICLES_CACHE",
+    time = 21600,
+    result = true,
+    sync_writes = true,
+    key = "String",
+    convert = r#"{ format!("{:?}_{}_{}", kind, term, lang) }"#
+)]
+async fn cached_articles_by_term(
+    kind: TaxonomyKind,
+    term: String,
+    lang: String,
+) -> Result<std::sync::Arc<Vec<ArticleWithMetadata>>, ServerFnError> {
+    let mut articles = content_index()
+        .articles_for_term(kind, &term, Some(&lang))
+        .ok_or_else(|| ServerFnError::new(format!("{} not found: {}", kind.label(), term)))?;
+
+    articles.sort_by(compare_by_date_desc);
+
+    Ok(std::sync::Arc::new(articles))
+}
@@ -1,67 +1,445 @@
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
 use std::time::Duration;
-use notify_debouncer_full::{new_debouncer, notify::*, DebounceEventResult};
-
-/// Start watching the articles directory for changes
-/// Changes will be reflected within 5 seconds due to cache TTL
-#[cfg(feature = "server")]
-pub fn start_article_watcher() -> std::io::Result<()> {
-    use std::thread;
-
-    let articles_path = Path::new("articles");
-
-    // Create a debouncer to avoid multiple rapid events
-    let mut debouncer = new_debouncer(
-        Duration::from_secs(2),
-        None,
-        move |result: DebounceEventResult| {
-            match result {
-                Ok(events) => {
-                    for event in events {
-                        // Check if it's a markdown file
-                        let is_markdown = event.paths.iter().any(|path| {
-                            path.extension()
-                                .and_then(|s| s.to_str())
-                                .map(|s| s == "md")
-                                .unwrap_or(false)
-                        });
-
-                        if is_markdown {
-                            dioxus::logger::tracing::info!(
-                                "Article file changed: {:?} - Changes will appear within 5 seconds",
-                                event.paths
-                            );
-                        }
-                    }
+use notify_debouncer_full::{
+    new_debouncer, new_debouncer_opt, notify::*, DebounceEventResult, Debouncer, FileIdMap,
+    RecommendedCache,
+};
+
+use super::authors::invalidate_author_registry;
+use super::index::{rebuild_content_index, update_content_index};
+use super::live_reload::publish_changes;
+use super::local::{invalidate_caches, ContentChanges};
+use super::reading::invalidate_reading_list;
+use super::site::{reload_site_config, site_config, site_config_path};
+
+/// How often the service checks that the articles directory still exists
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long events are collected before they are handled
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// How the watcher learns about file changes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WatchBackend {
+    /// The platform's native notifications (inotify, FSEvents, ...)
+    Native,
+    /// Rescan the tree at a fixed interval, for filesystems where
+    /// notifications don't arrive (network shares, some Docker volume mounts)
+    Poll(Duration),
+}
+
+/// What the watcher service watches and how
+#[derive(Clone, Debug)]
+pub struct WatcherConfig {
+    pub articles_dir: PathBuf,
+    /// Site-level files next to the articles directory
+    pub about_me: PathBuf,
+    pub authors: PathBuf,
+    pub taxonomy: PathBuf,
+    pub reading: PathBuf,
+    /// `site.toml`; the content paths follow it when it changes
+    pub site: PathBuf,
+    pub backend: WatchBackend,
+}
+
+impl WatcherConfig {
+    /// Paths from the site config, with the backend chosen by `WATCHER_BACKEND=poll`
+    /// and `WATCHER_POLL_INTERVAL` (seconds, default 2)
+    pub fn from_env() -> Self {
+        let backend = match std::env::var("WATCHER_BACKEND").as_deref() {
+            Ok("poll") => {
+                let seconds = std::env::var("WATCHER_POLL_INTERVAL")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(2);
+                WatchBackend::Poll(Duration::from_secs(seconds))
+            }
+            _ => WatchBackend::Native,
+        };
+
+        let content = site_config().content.clone();
+        WatcherConfig {
+            articles_dir: PathBuf::from(content.articles),
+            about_me: PathBuf::from(content.about_me),
+            authors: PathBuf::from(content.authors),
+            taxonomy: PathBuf::from(content.taxonomy),
+            reading: PathBuf::from(content.reading),
+            site: site_config_path(),
+            backend,
+        }
+    }
+}
+
+/// Messages handled by the service thread
+enum ServiceMessage {
+    /// Absolute paths from a batch of debounced events
+    Changed(Vec<PathBuf>),
+    Shutdown,
+}
+
+/// A debouncer over either watcher backend
+enum ActiveDebouncer {
+    Native(Debouncer<RecommendedWatcher, RecommendedCache>),
+    Poll(Debouncer<PollWatcher, FileIdMap>),
+}
+
+impl ActiveDebouncer {
+    fn new(backend: WatchBackend, sender: Sender<ServiceMessage>) -> Result<Self> {
+        let handler = move |result: DebounceEventResult| match result {
+            Ok(events) => {
+                // Reading files while indexing produces access events; ignore them
+                let paths: Vec<PathBuf> = events
+                    .iter()
+                    .filter(|event| !matches!(event.kind, EventKind::Access(_)))
+                    .flat_map(|event| event.paths.iter().cloned())
+                    .collect();
+
+                if !paths.is_empty() {
+                    let _ = sender.send(ServiceMessage::Changed(paths));
                 }
-                Err(errors) => {
-                    for error in errors {
-                        dioxus::logger::tracing::error!("File watcher error: {:?}", error);
-                    }
+            }
+            Err(errors) => {
+                for error in errors {
+                    dioxus::logger::tracing::error!("File watcher error: {:?}", error);
                 }
             }
-        },
-    ).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-
-    // Watch the articles directory recursively
-    debouncer
-        .watch(articles_path, RecursiveMode::Recursive)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-
-    dioxus::logger::tracing::info!(
-        "Started watching articles directory: {:?}",
-        articles_path
-    );
-
-    // Keep the debouncer alive in a background thread
-    // The debouncer must stay in scope for the watcher to work
-    thread::spawn(move || {
-        let _debouncer = debouncer;
-        // Keep the thread alive indefinitely to maintain the file watcher
+        };
+
+        match backend {
+            WatchBackend::Native => {
+                new_debouncer(DEBOUNCE_TIMEOUT, None, handler).map(ActiveDebouncer::Native)
+            }
+            WatchBackend::Poll(interval) => new_debouncer_opt::<_, PollWatcher, FileIdMap>(
+                DEBOUNCE_TIMEOUT,
+                None,
+                handler,
+                FileIdMap::new(),
+                Config::default().with_poll_interval(interval),
+            )
+            .map(ActiveDebouncer::Poll),
+        }
+    }
+
+    fn watch(&mut self, path: &Path, mode: RecursiveMode) -> Result<()> {
+        match self {
+            ActiveDebouncer::Native(debouncer) => debouncer.watch(path, mode),
+            ActiveDebouncer::Poll(debouncer) => debouncer.watch(path, mode),
+        }
+    }
+
+    fn unwatch(&mut self, path: &Path) -> Result<()> {
+        match self {
+            ActiveDebouncer::Native(debouncer) => debouncer.unwatch(path),
+            ActiveDebouncer::Poll(debouncer) => debouncer.unwatch(path),
+        }
+    }
+}
+
+/// Background service that keeps the content index, caches and live reload
+/// in step with `articles/` (including `summary.md` files), `aboutme.md`, `authors.toml`,
+/// `taxonomy.toml`, `reading.toml` and `site.toml`
+///
+/// The articles directory is re-watched automatically after it is deleted and
+/// recreated. Dropping the service (or calling [`WatcherService::shutdown`])
+/// stops the watcher and joins its thread.
+pub struct WatcherService {
+    sender: Sender<ServiceMessage>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WatcherService {
+    /// Start watching according to `config`
+    pub fn start(config: WatcherConfig) -> std::io::Result<Self> {
+        let to_io_error = |e: Error| std::io::Error::new(std::io::ErrorKind::Other, e);
+
+        let (sender, receiver) = mpsc::channel();
+        let debouncer = ActiveDebouncer::new(config.backend, sender.clone()).map_err(to_io_error)?;
+
+        // Apply changes on a dedicated runtime; the server's runtime isn't up yet
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        let mut state = ServiceState {
+            articles_root: std::path::absolute(&config.articles_dir)?,
+            about_me: std::path::absolute(&config.about_me)?,
+            authors: std::path::absolute(&config.authors)?,
+            taxonomy: std::path::absolute(&config.taxonomy)?,
+            reading: std::path::absolute(&config.reading)?,
+            site: std::path::absolute(&config.site)?,
+            debouncer,
+            watching_root: false,
+        };
+        state.watch_site_dir().map_err(to_io_error)?;
+        state.watch_root();
+
+        dioxus::logger::tracing::info!(
+            "Started {:?} watcher for {:?}",
+            config.backend,
+            state.articles_root
+        );
+
+        let thread = thread::Builder::new()
+            .name("article-watcher".to_string())
+            .spawn(move || state.run(receiver, runtime))?;
+
+        Ok(WatcherService {
+            sender,
+            thread: Some(thread),
+        })
+    }
+
+    /// Shut the service down and exit when the process gets Ctrl+C or SIGTERM
+    ///
+    /// The server never returns from `dioxus::serve`, so without this the
+    /// service would never be dropped.
+    pub fn shutdown_on_signal(self) -> std::io::Result<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        thread::Builder::new()
+            .name("shutdown-signal".to_string())
+            .spawn(move || {
+                runtime.block_on(wait_for_shutdown_signal());
+                dioxus::logger::tracing::info!("Shutting down article watcher");
+                self.shutdown();
+                std::process::exit(0);
+            })?;
+        Ok(())
+    }
+
+    /// Stop watching and wait for in-flight updates to finish
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        let _ = self.sender.send(ServiceMessage::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                dioxus::logger::tracing::error!("Article watcher thread panicked");
+            }
+        }
+    }
+}
+
+impl Drop for WatcherService {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Resolves on Ctrl+C, or on SIGTERM from `docker stop` and service managers
+async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    if let Ok(mut terminate) = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+        let ctrl_c = std::pin::pin!(tokio::signal::ctrl_c());
+        let terminate = std::pin::pin!(terminate.recv());
+        futures::future::select(ctrl_c, terminate).await;
+        return;
+    }
+
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        dioxus::logger::tracing::error!("Failed to listen for Ctrl+C: {}", e);
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Everything the service thread owns
+struct ServiceState {
+    articles_root: PathBuf,
+    about_me: PathBuf,
+    authors: PathBuf,
+    taxonomy: PathBuf,
+    reading: PathBuf,
+    site: PathBuf,
+    debouncer: ActiveDebouncer,
+    /// Whether a recursive watch on the articles directory is active
+    watching_root: bool,
+}
+
+impl ServiceState {
+    /// Watch the directories holding the site-level files (non-recursively);
+    /// the articles' parent also reports the articles directory being removed
+    /// or recreated
+    fn watch_site_dir(&mut self) -> Result<()> {
+        for dir in self.site_dirs() {
+            self.debouncer.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
+        Ok(())
+    }
+
+    /// Directories of the articles folder, `site.toml` and the other site-level
+    /// files, which the config may spread around
+    fn site_dirs(&self) -> BTreeSet<PathBuf> {
+        [&self.articles_root, &self.site, &self.about_me, &self.authors, &self.taxonomy, &self.reading]
+            .into_iter()
+            .map(|path| path.parent().unwrap_or(Path::new("/")).to_path_buf())
+            .collect()
+    }
+
+    /// Move the watch to the content paths of a reloaded site config
+    fn follow_content_paths(&mut self) {
+        let content = site_config().content.clone();
+        let paths = [&content.articles, &content.about_me, &content.authors, &content.taxonomy, &content.reading]
+            .map(std::path::absolute);
+        if let Some(Err(e)) = paths.iter().find(|path| path.is_err()) {
+            dioxus::logger::tracing::error!("Invalid content paths in site config: {}", e);
+            return;
+        }
+        let [articles_root, about_me, authors, taxonomy, reading] = paths.map(|path| path.expect("checked above"));
+
+        if self.watching_root {
+            let _ = self.debouncer.unwatch(&self.articles_root);
+            self.watching_root = false;
+        }
+        let old_dirs = self.site_dirs();
+        self.articles_root = articles_root;
+        self.about_me = about_me;
+        self.authors = authors;
+        self.taxonomy = taxonomy;
+        self.reading = reading;
+
+        let new_dirs = self.site_dirs();
+        for dir in old_dirs.difference(&new_dirs) {
+            let _ = self.debouncer.unwatch(dir);
+        }
+        if let Err(e) = self.watch_site_dir() {
+            dioxus::logger::tracing::error!("Failed to watch the site directories: {}", e);
+        }
+        self.watch_root();
+    }
+
+    /// (Re-)establish the recursive watch on the articles directory
+    fn watch_root(&mut self) -> bool {
+        if self.watching_root {
+            let _ = self.debouncer.unwatch(&self.articles_root);
+            self.watching_root = false;
+        }
+
+        if !self.articles_root.is_dir() {
+            dioxus::logger::tracing::warn!(
+                "Articles directory {:?} is missing; waiting for it to reappear",
+                self.articles_root
+            );
+            return false;
+        }
+
+        match self.debouncer.watch(&self.articles_root, RecursiveMode::Recursive) {
+            Ok(()) => self.watching_root = true,
+            Err(e) => dioxus::logger::tracing::error!("Failed to watch {:?}: {:?}", self.articles_root, e),
+        }
+        self.watching_root
+    }
+
+    fn run(mut self, receiver: mpsc::Receiver<ServiceMessage>, runtime: tokio::runtime::Runtime) {
         loop {
-            thread::sleep(Duration::from_secs(60));
+            let rewatch = match receiver.recv_timeout(HEALTH_CHECK_INTERVAL) {
+                Ok(ServiceMessage::Changed(paths)) => {
+                    // The root itself showing up in events means it was removed or recreated
+                    let root_changed = paths.iter().any(|path| *path == self.articles_root);
+                    runtime.block_on(self.handle_changes(paths));
+                    root_changed
+                }
+                Ok(ServiceMessage::Shutdown) | Err(RecvTimeoutError::Disconnected) => break,
+                // Catch a root that vanished or came back without any event reaching us
+                Err(RecvTimeoutError::Timeout) => self.watching_root != self.articles_root.is_dir(),
+            };
+
+            if rewatch && self.watch_root() {
+                dioxus::logger::tracing::info!("Re-watching {:?}; rebuilding index", self.articles_root);
+                runtime.block_on(async { apply(rebuild_content_index().await).await });
+            }
         }
-    });
 
-    Ok(())
+        dioxus::logger::tracing::info!("Article watcher stopped");
+    }
+
+    async fn handle_changes(&mut self, paths: Vec<PathBuf>) {
+        let mut articles = Vec::new();
+        let mut about_me = false;
+        let mut authors = false;
+        let mut taxonomy = false;
+        let mut reading = false;
+        let mut site = false;
+
+        for path in paths {
+            if path == self.site {
+                site = true;
+            } else if path == self.about_me {
+                about_me = true;
+            } else if path == self.authors {
+                authors = true;
+            } else if path == self.taxonomy {
+                taxonomy = true;
+            } else if path == self.reading {
+                reading = true;
+            } else if let Ok(relative) = path.strip_prefix(&self.articles_root) {
+                // Skip editor folders such as `.obsidian`
+                let hidden = relative.components().any(|c| {
+                    matches!(c, Component::Normal(name) if name.to_string_lossy().starts_with('.'))
+                });
+                if !hidden && !relative.as_os_str().is_empty() {
+                    articles.push(relative.to_path_buf());
+                }
+            }
+        }
+        articles.sort();
+        articles.dedup();
+
+        if site {
+            dioxus::logger::tracing::info!("{:?} changed; reloading site settings", self.site);
+            if reload_site_config().await {
+                self.follow_content_paths();
+            }
+            about_me = true;
+        }
+
+        if authors {
+            // Author names are resolved into every article
+            dioxus::logger::tracing::info!("{:?} changed; rebuilding index", self.authors);
+            invalidate_author_registry().await;
+            apply(rebuild_content_index().await).await;
+        } else if taxonomy {
+            // Every article's tags are normalised against it
+            dioxus::logger::tracing::info!("{:?} changed; rebuilding index", self.taxonomy);
+            apply(rebuild_content_index().await).await;
+        } else if site {
+            // The site URL ends up in feeds and the sitemap, and the content may have moved
+            apply(rebuild_content_index().await).await;
+        } else if !articles.is_empty() {
+            dioxus::logger::tracing::info!("Article files changed: {:?}", articles);
+            apply(update_content_index(articles).await).await;
+        }
+
+        if reading {
+            // Only the reading page uses it, so the index stays as it is
+            dioxus::logger::tracing::info!("{:?} changed", self.reading);
+            invalidate_reading_list().await;
+        }
+
+        if about_me {
+            dioxus::logger::tracing::info!("aboutme.md changed");
+            apply(Ok(ContentChanges {
+                about_me: true,
+                ..Default::default()
+            }))
+            .await;
+        }
+    }
+}
+
+/// Invalidate caches and notify live-reload clients about an index update
+async fn apply(result: std::io::Result<ContentChanges>) {
+    match result {
+        Ok(changes) => {
+            invalidate_caches(&changes).await;
+            publish_changes(&changes);
+        }
+        Err(e) => dioxus::logger::tracing::error!("Failed to update content index: {}", e),
+    }
 }
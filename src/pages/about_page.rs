@@ -1,10 +1,14 @@
+use crate::markdown_management::fetch_about_me;
+use crate::markdown_management::static_data::exported;
 use dioxus::prelude::*;
 use dioxus_markdown::Markdown;
 
-const ABOUT_ME_CONTENT: &str = include_str!("../../aboutme.md");
-
+/// The about page, read from the file named in `site.toml`
 #[component]
 pub fn AboutPage() -> Element {
+    let about_data = use_server_future(|| async move { exported!(fetch_about_me()).await.ok() })?;
+    let content = about_data.read().as_ref().cloned().flatten();
+
     rsx! {
         main {
             class: "flex-1 overflow-y-auto",
@@ -22,8 +26,11 @@ pub fn AboutPage() -> Element {
                         class: "rounded-2xl border border-base-300 bg-base-100 p-8 shadow-sm",
                         section {
                             class: "prose prose-lg max-w-none prose-headings:text-primary",
-                            Markdown {
-                                content: ABOUT_ME_CONTENT.to_string()
+                            match content {
+                                Some(content) => rsx! { Markdown { content } },
+                                None => rsx! {
+                                    p { class: "text-base-content/60", "Nothing here yet." }
+                                },
                             }
                         }
                     }
@@ -0,0 +1,192 @@
+use dioxus::prelude::*;
+use crate::markdown_management::{fetch_archive, ArchiveMonth, ArchiveYear};
+use crate::markdown_management::static_data::exported;
+use crate::shared::locale::use_locale;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+pub(crate) fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES.get(month.wrapping_sub(1) as usize).copied().unwrap_or("Unknown")
+}
+
+/// The archive, or one year or month of it
+#[component]
+pub fn ArchivePage(year: Option<i32>, month: Option<u32>) -> Element {
+    let locale = use_locale();
+
+    let archive = use_server_future(move || async move {
+        let locale = locale();
+        exported!(fetch_archive(year, month, locale)).await.ok()
+    })?;
+
+    let heading = match (year, month) {
+        (Some(year), Some(month)) => format!("{} {}", month_name(month), year),
+        (Some(year), None) => format!("{}", year),
+        _ => "Archive".to_string(),
+    };
+    // Narrowed views open every section; the full archive opens only the latest year
+    let expand_all = year.is_some();
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-4xl",
+
+                // Header
+                div {
+                    class: "mb-8",
+                    div {
+                        class: "breadcrumbs text-sm mb-2",
+                        ul {
+                            li {
+                                Link { to: crate::Route::Archive {}, "Archive" }
+                            }
+                            if let Some(year) = year {
+                                li {
+                                    Link { to: crate::Route::ArchiveYear { year }, "{year}" }
+                                }
+                            }
+                            if let (Some(_), Some(month)) = (year, month) {
+                                li { "{month_name(month)}" }
+                            }
+                        }
+                    }
+                    h1 {
+                        class: "text-3xl font-bold mb-4",
+                        "{heading}"
+                    }
+                    p {
+                        class: "text-lg text-base-content opacity-70",
+                        "Every published article, series parts included, by date"
+                    }
+                }
+
+                match archive.read().as_ref() {
+                    Some(Some(years)) if years.is_empty() => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-base-content opacity-70",
+                                "No articles published in this period"
+                            }
+                        }
+                    },
+                    Some(Some(years)) => rsx! {
+                        div {
+                            class: "space-y-4",
+                            for (index, archive_year) in years.iter().enumerate() {
+                                YearSection {
+                                    key: "{archive_year.year}",
+                                    archive_year: archive_year.clone(),
+                                    open: expand_all || index == 0,
+                                    expand_months: expand_all,
+                                }
+                            }
+                        }
+                    },
+                    Some(None) => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-error",
+                                "Failed to load the archive"
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn YearSection(archive_year: ArchiveYear, open: bool, expand_months: bool) -> Element {
+    let year = archive_year.year;
+    let article_label = if archive_year.count == 1 { "article" } else { "articles" };
+
+    rsx! {
+        details {
+            class: "collapse collapse-arrow bg-base-200",
+            open,
+            summary {
+                class: "collapse-title text-xl font-bold flex items-center gap-3",
+                "{year}"
+                span { class: "badge badge-primary", "{archive_year.count} {article_label}" }
+            }
+            div {
+                class: "collapse-content space-y-2",
+                Link {
+                    to: crate::Route::ArchiveYear { year },
+                    class: "link link-hover link-primary text-sm",
+                    "All of {year} →"
+                }
+                for archive_month in archive_year.months.iter() {
+                    MonthSection {
+                        key: "{archive_month.month}",
+                        year,
+                        archive_month: archive_month.clone(),
+                        open: expand_months,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn MonthSection(year: i32, archive_month: ArchiveMonth, open: bool) -> Element {
+    let month = archive_month.month;
+
+    rsx! {
+        details {
+            class: "collapse collapse-plus bg-base-100",
+            open,
+            summary {
+                class: "collapse-title font-semibold flex items-center gap-3",
+                "{month_name(month)}"
+                span { class: "badge badge-ghost badge-sm", "{archive_month.entries.len()}" }
+            }
+            div {
+                class: "collapse-content",
+                ul {
+                    class: "space-y-2",
+                    for entry in archive_month.entries.iter() {
+                        li {
+                            key: "{entry.path}",
+                            class: "flex flex-wrap items-baseline gap-3",
+                            span {
+                                class: "text-sm text-base-content opacity-60 w-24 shrink-0",
+                                {entry.date.date().format("%e %b").to_string()}
+                            }
+                            Link {
+                                to: format!("/article/{}", entry.path.trim_end_matches(".md")),
+                                class: "link link-hover font-medium",
+                                "{entry.title}"
+                            }
+                            if let Some(ref series) = entry.series {
+                                span { class: "badge badge-secondary badge-sm", "{series}" }
+                            }
+                        }
+                    }
+                }
+                Link {
+                    to: crate::Route::ArchiveMonth { year, month },
+                    class: "link link-hover link-primary text-sm mt-3 inline-block",
+                    "Just {month_name(month)} {year} →"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use dioxus::prelude::*;
+
+use crate::markdown_management::{ArticleRevision, fetch_article_history};
+use crate::markdown_management::static_data::exported;
+
+/// Revision history tab listing the commits that touched the article
+#[component]
+pub fn HistoryTab(path: String) -> Element {
+    let history = use_resource(move || {
+        let path = path.clone();
+        async move { exported!(fetch_article_history(path)).await }
+    });
+
+    rsx! {
+        div {
+            class: "p-8",
+            h2 {
+                class: "text-xl font-bold mb-6",
+                "Revision History"
+            }
+
+            match history.read().as_ref() {
+                Some(Ok(revisions)) if revisions.is_empty() => rsx! {
+                    p {
+                        class: "text-base-content opacity-70",
+                        "No revision history is available for this article."
+                    }
+                },
+                Some(Ok(revisions)) => rsx! {
+                    ul {
+                        class: "timeline timeline-vertical timeline-compact",
+                        for revision in revisions.iter() {
+                            RevisionItem {
+                                key: "{revision.commit}",
+                                revision: revision.clone()
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "alert alert-error",
+                        "Failed to load history: {e}"
+                    }
+                },
+                None => rsx! {
+                    div {
+                        class: "flex justify-center py-8",
+                        span { class: "loading loading-spinner loading-md" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RevisionItem(revision: ArticleRevision) -> Element {
+    let mut show_diff = use_signal(|| false);
+
+    rsx! {
+        li {
+            div {
+                class: "timeline-end card card-sm bg-base-200 w-full mb-4",
+                div {
+                    class: "card-body",
+                    div {
+                        class: "flex flex-wrap items-center gap-2 text-sm",
+                        span { class: "badge badge-ghost font-mono", "{revision.short_commit}" }
+                        span { class: "opacity-70", "{revision.date.to_long_string()}" }
+                        span { class: "opacity-70", "by {revision.author}" }
+                    }
+                    p {
+                        class: "font-semibold",
+                        "{revision.message}"
+                    }
+                    if !revision.diff.is_empty() {
+                        button {
+                            class: "btn btn-xs btn-ghost w-fit",
+                            onclick: move |_| show_diff.toggle(),
+                            if show_diff() { "Hide changes" } else { "Show changes" }
+                        }
+                        if show_diff() {
+                            // Shown as plain text: a diff of Markdown is not Markdown itself
+                            pre {
+                                class: "bg-base-300 rounded-box p-4 text-xs overflow-x-auto",
+                                code {
+                                    class: "language-diff",
+                                    "{revision.diff}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
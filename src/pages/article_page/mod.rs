@@ -1,10 +1,41 @@
+use dioxus::fullstack::FullstackContext;
 use dioxus::{logger, prelude::*};
 use dioxus_markdown::Markdown;
 
-use crate::markdown_management::{ArticleTomlMetadata, fetch_article_with_metadata};
+use crate::markdown_management::{
+    Author, ArticleTomlMetadata, TaxonomyKind, fetch_article_cached, record_progress, refresh_cached_article,
+    watch_content_changes,
+};
+use crate::markdown_management::static_data::exported_languages;
+use crate::pages::taxonomy_page::TermBadge;
 
+mod history;
+mod translations;
+
+use history::HistoryTab;
+use translations::{HreflangLinks, LanguageSwitcher};
+
+/// An article, rendered on the server so the first response already has its content
 #[component]
 pub fn ArticlePage(path: String) -> Element {
+    rsx! {
+        SuspenseBoundary {
+            fallback: |_| rsx! {
+                main {
+                    class: "flex-1 overflow-y-auto p-4 md:p-8",
+                    div {
+                        class: "container mx-auto max-w-4xl",
+                        ArticleSkeleton {}
+                    }
+                }
+            },
+            ArticleView { path }
+        }
+    }
+}
+
+#[component]
+fn ArticleView(path: String) -> Element {
     let active_tab = use_signal(|| "article".to_string()); //TODO: Use enum
 
     logger::tracing::info!(
@@ -21,14 +52,23 @@ pub fn ArticlePage(path: String) -> Element {
         current_path.set(path.clone());
     });
 
-    // Fetch article with metadata from server
-    // Resource will restart when current_path signal changes
-    let article_data = use_resource(move || {
+    // Set by live reload to skip the offline cache for the next fetch
+    let mut bypass_cache = use_signal(|| false);
+
+    // Fetch article with metadata, from the offline cache when available
+    // Prefetched during server rendering and restarted when current_path changes
+    let mut article_data = use_server_future(move || {
         let path_to_fetch = current_path();
+        let bypass = *bypass_cache.peek();
 
         async move {
             logger::tracing::info!("Fetching article: {}", path_to_fetch);
-            let result = fetch_article_with_metadata(path_to_fetch.clone()).await;
+            let result = if bypass {
+                bypass_cache.set(false);
+                refresh_cached_article(path_to_fetch.clone()).await
+            } else {
+                fetch_article_cached(path_to_fetch.clone()).await
+            };
             match &result {
                 Ok(_) => {
                     logger::tracing::info!("Successfully fetched article: {}", path_to_fetch)
@@ -37,7 +77,88 @@ pub fn ArticlePage(path: String) -> Element {
                     logger::tracing::error!("Failed to fetch article {}: {:?}", path_to_fetch, e)
                 }
             }
-            result
+            // Errors are sent to the client with the server-rendered page, keeping
+            // the server's status code
+            result.map_err(|e| {
+                e.downcast::<ServerFnError>()
+                    .unwrap_or_else(|e| ServerFnError::new(format!("{:#}", e)))
+            })
+        }
+    })?;
+
+    // A missing or forbidden article answers with its status during server rendering
+    if let Some(Err(ServerFnError::ServerError { message, code, .. })) = &*article_data.read() {
+        let status = StatusCode::from_u16(*code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        FullstackContext::commit_http_status(status, Some(message.clone()));
+    }
+
+    // Scroll position to restore once a live-reloaded article has rendered
+    let mut restore_scroll = use_signal(|| None::<f64>);
+
+    // Live reload: re-fetch in place when the server reports this file changed
+    // The server rejects the subscription unless live reload is enabled
+    use_future(move || async move {
+        if exported_languages().is_some() {
+            return;
+        }
+        let Ok(mut changes) = watch_content_changes().await else {
+            return;
+        };
+
+        while let Some(Ok(change)) = changes.recv().await {
+            if change.affects(&current_path.peek()) {
+                logger::tracing::info!("Article changed on disk, reloading: {}", current_path.peek());
+                let scroll_top = document::eval(
+                    "dioxus.send(document.getElementById('article-scroll')?.scrollTop ?? 0);",
+                )
+                .recv::<f64>()
+                .await
+                .unwrap_or_default();
+                restore_scroll.set(Some(scroll_top));
+                bypass_cache.set(true);
+                article_data.restart();
+            }
+        }
+    });
+
+    // Reading progress: record how far the reader scrolls, at most once a second
+    use_future(move || async move {
+        let mut scroll = document::eval(
+            r#"
+            const el = document.getElementById('article-scroll');
+            if (el) {
+                let pending = null;
+                el.addEventListener('scroll', () => {
+                    if (pending) return;
+                    pending = setTimeout(() => {
+                        pending = null;
+                        const max = el.scrollHeight - el.clientHeight;
+                        dioxus.send(max > 0 ? el.scrollTop / max : 1);
+                    }, 1000);
+                });
+            }
+            "#,
+        );
+
+        while let Ok(fraction) = scroll.recv::<f64>().await {
+            let article = match &*article_data.peek() {
+                Some(Ok(article)) => article.clone(),
+                _ => continue,
+            };
+            if let Err(e) = record_progress(&article, fraction).await {
+                logger::tracing::warn!("Failed to record reading progress: {}", e);
+            }
+        }
+    });
+
+    use_effect(move || {
+        let loaded = matches!(&*article_data.read(), Some(Ok(_)));
+        if let (true, Some(scroll_top)) = (loaded, restore_scroll()) {
+            document::eval(&format!(
+                "requestAnimationFrame(() => {{ const el = document.getElementById('article-scroll'); if (el) el.scrollTop = {}; }});",
+                scroll_top
+            ));
+            restore_scroll.set(None);
         }
     });
 
@@ -47,6 +168,7 @@ pub fn ArticlePage(path: String) -> Element {
 
             // Main content area
             div {
+                id: "article-scroll",
                 class: "flex-1 overflow-y-auto p-4 md:p-8 pb-32 md:pb-8",
                 div {
                     class: "container mx-auto max-w-4xl",
@@ -62,10 +184,22 @@ pub fn ArticlePage(path: String) -> Element {
                                     div {
                                         class: "space-y-6",
 
+                                        // Alternate language versions
+                                        if article.metadata.translations.len() > 1 {
+                                            HreflangLinks {
+                                                translations: article.metadata.translations.clone()
+                                            }
+                                            LanguageSwitcher {
+                                                current: article.metadata.lang.clone(),
+                                                translations: article.metadata.translations.clone()
+                                            }
+                                        }
+
                                         // Article metadata
                                         if let Some(ref meta) = article.toml_metadata {
                                             ArticleMetadata {
-                                                metadata: meta.clone()
+                                                metadata: meta.clone(),
+                                                authors: article.authors.clone()
                                             }
                                         }
 
@@ -102,9 +236,10 @@ pub fn ArticlePage(path: String) -> Element {
                                                                 div {
                                                                     class: "flex flex-wrap gap-2",
                                                                     for tag in &meta.tags {
-                                                                        span {
+                                                                        TermBadge {
+                                                                            kind: TaxonomyKind::Tag,
+                                                                            term: tag.clone(),
                                                                             class: "badge h-fit badge-outline badge-lg",
-                                                                            "#{tag}"
                                                                         }
                                                                     }
                                                                 }
@@ -239,6 +374,11 @@ pub fn ArticlePage(path: String) -> Element {
                                                         }
                                                     }
                                                 },
+                                                "history" => rsx! {
+                                                    HistoryTab {
+                                                        path: article.metadata.path.clone()
+                                                    }
+                                                },
                                                 _ => rsx! {
                                                     div {
                                                         class: "p-8",
@@ -250,11 +390,17 @@ pub fn ArticlePage(path: String) -> Element {
                                     }
                                 }
                             },
-                            Some(Err(e)) => rsx! {
-                                div {
-                                    class: "alert alert-error",
-                                    h3 { "Error Loading Article" }
-                                    p { "{e}" }
+                            Some(Err(e)) => {
+                                let message = match e {
+                                    ServerFnError::ServerError { message, .. } => message.clone(),
+                                    other => other.to_string(),
+                                };
+                                rsx! {
+                                    div {
+                                        class: "alert alert-error",
+                                        h3 { "Error Loading Article" }
+                                        p { "{message}" }
+                                    }
                                 }
                             },
                             None => rsx! {
@@ -451,6 +597,19 @@ fn RightSidebar(active_tab: Signal<String>, metadata: Option<ArticleTomlMetadata
                             "✓ Quiz"
                         }
                     }
+
+                    // History tab
+                    if metadata.as_ref().map(|m| m.show_history).unwrap_or(true) {
+                        button {
+                            class: if active_tab.read().as_str() == "history" {
+                                "btn btn-sm btn-primary justify-start"
+                            } else {
+                                "btn btn-sm btn-ghost justify-start"
+                            },
+                            onclick: move |_| active_tab.set("history".to_string()),
+                            "🕘 History"
+                        }
+                    }
                 }
             }
         }
@@ -458,7 +617,7 @@ fn RightSidebar(active_tab: Signal<String>, metadata: Option<ArticleTomlMetadata
 }
 
 #[component]
-fn ArticleMetadata(metadata: ArticleTomlMetadata) -> Element {
+fn ArticleMetadata(metadata: ArticleTomlMetadata, authors: Vec<Author>) -> Element {
     rsx! {
         div {
             class: "bg-base-200 rounded-lg p-6 space-y-4",
@@ -466,7 +625,22 @@ fn ArticleMetadata(metadata: ArticleTomlMetadata) -> Element {
             // Author and date
             div {
                 class: "flex flex-wrap items-center gap-4 text-sm",
-                if let Some(ref author) = metadata.author {
+                if !authors.is_empty() {
+                    div {
+                        class: "flex items-center gap-2 font-semibold",
+                        "By"
+                        for (index, author) in authors.iter().enumerate() {
+                            if index > 0 {
+                                span { "," }
+                            }
+                            Link {
+                                to: format!("/author/{}", author.id),
+                                class: "link link-hover link-primary",
+                                "{author.name}"
+                            }
+                        }
+                    }
+                } else if let Some(ref author) = metadata.author {
                     div {
                         class: "flex items-center gap-2",
                         span {
@@ -475,12 +649,22 @@ fn ArticleMetadata(metadata: ArticleTomlMetadata) -> Element {
                         }
                     }
                 }
-                if let Some(ref date) = metadata.date {
+                if let Some(date) = metadata.date {
+                    div {
+                        class: "flex items-center gap-2",
+                        span {
+                            class: "badge badge-ghost",
+                            "📅 {date.to_long_string()}"
+                        }
+                    }
+                }
+                // Only worth showing when it falls on a later day than the publication date
+                if let Some(updated) = metadata.updated.filter(|u| metadata.date.is_none_or(|d| u.date() > d.date())) {
                     div {
                         class: "flex items-center gap-2",
                         span {
                             class: "badge badge-ghost",
-                            "📅 {date}"
+                            "✏️ Updated on {updated.to_long_string()}"
                         }
                     }
                 }
@@ -507,15 +691,17 @@ fn ArticleMetadata(metadata: ArticleTomlMetadata) -> Element {
             div {
                 class: "flex flex-wrap gap-2",
                 if let Some(ref category) = metadata.category {
-                    span {
+                    TermBadge {
+                        kind: TaxonomyKind::Category,
+                        term: category.clone(),
                         class: "badge badge-primary badge-lg",
-                        "{category}"
                     }
                 }
                 for topic in &metadata.topics {
-                    span {
+                    TermBadge {
+                        kind: TaxonomyKind::Topic,
+                        term: topic.clone(),
                         class: "badge badge-secondary badge-lg",
-                        "{topic}"
                     }
                 }
             }
@@ -775,6 +961,31 @@ fn BottomNavPanel(active_tab: Signal<String>, metadata: Option<ArticleTomlMetada
                     }
                 }
             }
+
+            // History Tab (Conditional)
+            if metadata.as_ref().map(|m| m.show_history).unwrap_or(true) {
+                button {
+                    class: if active_tab.read().as_str() == "history" { "dock-active" } else { "" },
+                    onclick: move |_| active_tab.set("history".to_string()),
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        class: "h-5 w-5",
+                        fill: "none",
+                        view_box: "0 0 24 24",
+                        stroke: "currentColor",
+                        path {
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            stroke_width: "2",
+                            d: "M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z"
+                        }
+                    }
+                    span {
+                        class: "dock-label",
+                        "History"
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,69 @@
+use dioxus::prelude::*;
+
+use crate::markdown_management::{DEFAULT_LANGUAGE, Translation};
+use crate::shared::locale::{set_locale, use_locale};
+
+fn article_route(path: &str) -> String {
+    format!("/article/{}", path.trim_end_matches(".md"))
+}
+
+/// `<link rel="alternate" hreflang>` tags pointing at every translation
+#[component]
+pub fn HreflangLinks(translations: Vec<Translation>) -> Element {
+    let default = translations
+        .iter()
+        .find(|t| t.lang == DEFAULT_LANGUAGE)
+        .or(translations.first())
+        .map(|t| article_route(&t.path));
+
+    rsx! {
+        for translation in translations.iter() {
+            document::Link {
+                rel: "alternate",
+                hreflang: "{translation.lang}",
+                href: article_route(&translation.path)
+            }
+        }
+        if let Some(href) = default {
+            document::Link {
+                rel: "alternate",
+                hreflang: "x-default",
+                href: href
+            }
+        }
+    }
+}
+
+/// Buttons for switching between the language versions of an article
+#[component]
+pub fn LanguageSwitcher(current: String, translations: Vec<Translation>) -> Element {
+    let locale = use_locale();
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-2",
+            span {
+                class: "text-sm opacity-70",
+                "🌐 Read in:"
+            }
+            for translation in translations.into_iter() {
+                if translation.lang == current {
+                    span {
+                        class: "badge badge-primary",
+                        "{translation.lang}"
+                    }
+                } else {
+                    Link {
+                        to: article_route(&translation.path),
+                        class: "badge badge-outline hover:badge-primary",
+                        onclick: {
+                            let lang = translation.lang.clone();
+                            move |_| set_locale(locale, lang.clone())
+                        },
+                        "{translation.lang}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,20 @@
 use dioxus::prelude::*;
-use crate::markdown_management::{fetch_standalone_articles, ArticleWithMetadata};
-
-const ARTICLES_PER_PAGE: usize = 12;
+use crate::markdown_management::{fetch_standalone_articles, ArticleWithMetadata, PaginatedArticles};
+use crate::markdown_management::static_data::exported;
+use crate::shared::locale::use_locale;
 
 #[component]
 pub fn ArticlesPage() -> Element {
     let mut current_page = use_signal(|| 1usize);
+    let locale = use_locale();
 
-    // Fetch paginated articles from server
-    let articles_data = use_resource(move || async move {
+    // Fetch paginated articles, prefetched during server rendering
+    let articles_data = use_server_future(move || async move {
         let page = current_page.read().clone();
-        fetch_standalone_articles(page, ARTICLES_PER_PAGE).await.ok()
-    });
+        let per_page = PaginatedArticles::PER_PAGE;
+        let locale = locale();
+        exported!(fetch_standalone_articles(page, per_page, locale)).await.ok()
+    })?;
 
     rsx! {
         main {
@@ -30,6 +33,19 @@ pub fn ArticlesPage() -> Element {
                         class: "text-lg text-base-content opacity-70",
                         "Browse articles that are not part of any series"
                     }
+                    div {
+                        class: "flex gap-2 mt-4",
+                        Link {
+                            to: crate::Route::Archive {},
+                            class: "btn btn-sm btn-outline",
+                            "Browse by date"
+                        }
+                        Link {
+                            to: crate::Route::Tags {},
+                            class: "btn btn-sm btn-outline",
+                            "Browse by subject"
+                        }
+                    }
                 }
 
                 // Content
@@ -109,7 +125,7 @@ pub fn ArticlesPage() -> Element {
 }
 
 #[component]
-fn ArticleCard(article: ArticleWithMetadata) -> Element {
+pub(crate) fn ArticleCard(article: ArticleWithMetadata) -> Element {
     let thumbnail = article.toml_metadata.as_ref()
         .and_then(|m| m.thumbnail.clone());
 
@@ -215,7 +231,7 @@ fn ArticleCard(article: ArticleWithMetadata) -> Element {
 }
 
 #[component]
-fn Pagination(
+pub(crate) fn Pagination(
     current_page: usize,
     total_pages: usize,
     on_page_change: EventHandler<usize>
@@ -0,0 +1,178 @@
+use dioxus::prelude::*;
+use advanced_markdown_parser::encode_segment;
+use crate::markdown_management::{fetch_author_page, ArticleWithMetadata};
+use crate::markdown_management::static_data::exported;
+use crate::shared::seo::PageSeo;
+
+#[component]
+pub fn AuthorPage(author_id: String) -> Element {
+    let seo_path = format!("/author/{}", encode_segment(&author_id));
+
+    // Fetch author profile and their articles, prefetched during server rendering
+    let author_data = use_server_future(move || {
+        let id = author_id.clone();
+        async move { exported!(fetch_author_page(id)).await.ok() }
+    })?;
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-5xl",
+
+                match author_data.read().as_ref() {
+                    Some(Some(data)) => rsx! {
+                        // Titled with the registry's display name rather than the id
+                        PageSeo {
+                            path: seo_path.clone(),
+                            title: Some(data.author.name.clone()),
+                            description: data.author.bio.clone()
+                        }
+                        div {
+                            class: "space-y-8",
+
+                            // Profile header
+                            div {
+                                class: "flex flex-col sm:flex-row items-center sm:items-start gap-6 mb-8",
+                                if let Some(ref avatar) = data.author.avatar {
+                                    img {
+                                        src: "{avatar}",
+                                        alt: "{data.author.name}",
+                                        class: "w-32 h-32 rounded-2xl object-cover border-4 border-base-300 shadow-lg"
+                                    }
+                                }
+                                div {
+                                    class: "flex-1 text-center sm:text-left",
+                                    h1 {
+                                        class: "text-4xl font-bold mb-3",
+                                        "{data.author.name}"
+                                    }
+                                    if let Some(ref bio) = data.author.bio {
+                                        p {
+                                            class: "text-lg text-base-content opacity-70 mb-4",
+                                            "{bio}"
+                                        }
+                                    }
+                                    div {
+                                        class: "flex flex-wrap gap-2 justify-center sm:justify-start",
+                                        for link in data.author.links.iter() {
+                                            a {
+                                                href: "{link.url}",
+                                                target: "_blank",
+                                                rel: "noopener noreferrer",
+                                                class: "btn btn-sm btn-outline",
+                                                "{link.label}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Series
+                            if !data.series.is_empty() {
+                                div {
+                                    h2 {
+                                        class: "text-2xl font-bold mb-4",
+                                        "Series"
+                                    }
+                                    div {
+                                        class: "flex flex-wrap gap-2",
+                                        for series in data.series.iter() {
+                                            Link {
+                                                to: format!("/series/{}", series),
+                                                class: "badge badge-primary badge-lg hover:badge-secondary",
+                                                "{series}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Articles
+                            div {
+                                h2 {
+                                    class: "text-2xl font-bold mb-4",
+                                    "Articles"
+                                }
+                                if data.articles.is_empty() {
+                                    p {
+                                        class: "text-lg text-base-content opacity-70",
+                                        "No articles published yet."
+                                    }
+                                } else {
+                                    div {
+                                        class: "space-y-3",
+                                        for article in data.articles.iter() {
+                                            AuthorArticleCard { article: article.clone() }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(None) => rsx! {
+                        PageSeo { path: seo_path.clone(), title: Some("Author not found".to_string()), noindex: true }
+                        div {
+                            class: "text-center py-12",
+                            div {
+                                class: "alert alert-error max-w-md mx-auto",
+                                span { "Author not found" }
+                            }
+                            Link {
+                                to: "/",
+                                class: "btn btn-primary mt-4",
+                                "Back to Home"
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                            p {
+                                class: "mt-4 text-base-content opacity-70",
+                                "Loading author..."
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AuthorArticleCard(article: ArticleWithMetadata) -> Element {
+    rsx! {
+        Link {
+            to: format!("/article/{}", article.metadata.path.trim_end_matches(".md")),
+            class: "card card-compact bg-base-100 border-2 border-base-300 hover:border-primary hover:shadow-lg transition-all duration-300",
+            div {
+                class: "card-body",
+                h3 {
+                    class: "font-bold text-lg mb-1",
+                    "{article.metadata.title}"
+                }
+                if let Some(ref toml_meta) = article.toml_metadata {
+                    div {
+                        class: "flex flex-wrap gap-3 text-sm text-base-content opacity-70",
+                        if let Some(ref date) = toml_meta.date {
+                            span { "📅 {date.to_long_string()}" }
+                        }
+                        if let Some(ref series) = toml_meta.primary_series {
+                            span { "📚 {series}" }
+                        }
+                    }
+                    if let Some(ref summary) = toml_meta.summary {
+                        p {
+                            class: "text-base-content opacity-70 mt-2 line-clamp-2",
+                            "{summary}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
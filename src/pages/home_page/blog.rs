@@ -1,20 +1,81 @@
 use dioxus::prelude::*;
 
 use crate::markdown_management::{
-    ArticleWithMetadata, fetch_home_page_data_with_metadata,
+    ArticleWithMetadata, fetch_all_series, fetch_home_page_data_with_metadata,
+    fetch_series_progress, next_unread,
 };
+use crate::markdown_management::static_data::exported;
+use crate::shared::locale::use_locale;
 
 #[component]
 pub fn Blogs() -> Element {
-    // Fetch articles with metadata
-    let home_data = use_resource(|| async move {
-        fetch_home_page_data_with_metadata().await.ok()
+    let locale = use_locale();
+
+    // Fetch articles with metadata, prefetched during server rendering
+    let home_data = use_server_future(move || async move {
+        let locale = locale();
+        exported!(fetch_home_page_data_with_metadata(locale)).await.ok()
+    })?;
+
+    // Series the reader has started, with the next part to read, most recent first
+    let continue_reading = use_resource(move || async move {
+        let mut started = Vec::new();
+        let locale = locale();
+        for series in exported!(fetch_all_series(locale)).await.unwrap_or_default() {
+            let Ok(progress) = fetch_series_progress(series.name.clone()).await else {
+                continue;
+            };
+            let last_read = progress.values().map(|p| p.updated_at).fold(0.0, f64::max);
+            if let Some(next) = next_unread(&series, &progress) {
+                let part = series.articles.iter().position(|a| a.metadata.path == next.metadata.path).unwrap_or(0) + 1;
+                started.push((last_read, ContinueItem {
+                    series: series.name.clone(),
+                    part,
+                    total: series.articles.len(),
+                    article: next.clone(),
+                }));
+            }
+        }
+        started.sort_by(|a, b| b.0.total_cmp(&a.0));
+        started.into_iter().take(3).map(|(_, item)| item).collect::<Vec<_>>()
     });
 
     rsx! {
         div {
             class: "space-y-12",
-            
+
+            // Continue Reading Section (only once the reader has started a series)
+            if let Some(items) = continue_reading.read().as_ref().filter(|items| !items.is_empty()) {
+                div {
+                    h2 {
+                        class: "text-xl font-bold mb-6 flex items-center gap-2",
+                        span { class: "w-2 h-6 bg-accent rounded-full" }
+                        "Continue where you left off"
+                    }
+                    div {
+                        class: "grid gap-4 md:grid-cols-3",
+                        for item in items.iter() {
+                            Link {
+                                key: "{item.series}",
+                                to: format!("/article/{}", item.article.metadata.path.trim_end_matches(".md")),
+                                class: "rounded-xl p-5 border border-base-300 bg-base-100 transition-all hover:-translate-y-1 hover:shadow-lg hover:border-accent/50 group cursor-pointer block",
+                                p { class: "text-xs font-semibold uppercase tracking-wider text-accent mb-2", "{item.series}" }
+                                h3 {
+                                    class: "font-bold mb-2 group-hover:text-accent transition-colors line-clamp-2",
+                                    "{item.article.metadata.title}"
+                                }
+                                p { class: "text-xs text-base-content/50 mb-2", "Part {item.part} of {item.total}" }
+                                progress {
+                                    class: "progress progress-accent w-full",
+                                    value: "{item.part - 1}",
+                                    max: "{item.total}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // Featured Post Section
             div {
                 h2 {
@@ -90,14 +151,22 @@ pub fn Blogs() -> Element {
     }
 }
 
+/// The next unread part of a series the reader has started
+#[derive(Clone, PartialEq)]
+struct ContinueItem {
+    series: String,
+    /// 1-based position of `article` in the series
+    part: usize,
+    total: usize,
+    article: ArticleWithMetadata,
+}
+
 #[component]
 fn FeaturedArticle(article: ArticleWithMetadata) -> Element {
     let thumbnail = article.toml_metadata.as_ref().and_then(|m| m.thumbnail.clone());
-    let date = article.toml_metadata.as_ref().and_then(|m| m.date.clone()).unwrap_or_default();
+    let date = article.toml_metadata.as_ref().and_then(|m| m.date).map(|d| d.to_long_string()).unwrap_or_default();
     let category = article.toml_metadata.as_ref().and_then(|m| m.category.clone()).unwrap_or_else(|| "Deep Dive".to_string());
-    let summary = article.toml_metadata.as_ref().and_then(|m| m.summary.clone()).unwrap_or_else(|| {
-        article.content.chars().take(200).collect::<String>() + "..."
-    });
+    let summary = article.toml_metadata.as_ref().and_then(|m| m.summary.clone()).unwrap_or_else(|| article.excerpt.clone());
 
     rsx! {
         div {
@@ -156,12 +225,10 @@ fn FeaturedArticle(article: ArticleWithMetadata) -> Element {
 
 #[component]
 fn ArticleCard(article: ArticleWithMetadata) -> Element {
-    let date = article.toml_metadata.as_ref().and_then(|m| m.date.clone()).unwrap_or_default();
+    let date = article.toml_metadata.as_ref().and_then(|m| m.date).map(|d| d.to_long_string()).unwrap_or_default();
     let category = article.toml_metadata.as_ref().and_then(|m| m.category.clone()).unwrap_or_else(|| "Article".to_string());
     let read_time = article.toml_metadata.as_ref().and_then(|m| m.reading_time.clone()).unwrap_or_else(|| "5 min read".to_string());
-    let summary = article.toml_metadata.as_ref().and_then(|m| m.summary.clone()).unwrap_or_else(|| {
-        article.content.chars().take(150).collect::<String>() + "..."
-    });
+    let summary = article.toml_metadata.as_ref().and_then(|m| m.summary.clone()).unwrap_or_else(|| article.excerpt.clone());
 
     rsx! {
         Link {
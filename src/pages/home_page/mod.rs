@@ -1,10 +1,19 @@
 pub mod blog;
 pub mod projects;
+use crate::markdown_management::{fetch_site_config, fetch_site_owner, Author};
+use crate::markdown_management::static_data::exported;
 use crate::pages::home_page::{blog::Blogs, projects::Projects};
 use dioxus::prelude::*;
 
 #[component]
 pub fn HomePage() -> Element {
+    let owner_data = use_server_future(|| async move { exported!(fetch_site_owner()).await.ok() })?;
+    let owner = owner_data.read().as_ref().cloned().flatten();
+    let owner_name = owner.as_ref().map(|a| a.name.clone()).unwrap_or_default();
+    let site_data = use_server_future(|| async move { exported!(fetch_site_config()).await.unwrap_or_default() })?;
+    let site = site_data.read().clone().unwrap_or_default();
+    let copyright = site.copyright_for(&owner_name);
+
     rsx! {
         main {
             class: "flex-1 w-full overflow-y-auto",
@@ -13,7 +22,7 @@ pub fn HomePage() -> Element {
                 class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
                 
                 // Hero Section
-                Hero {}
+                Hero { owner: owner.clone() }
 
                 // Main Content + Sidebar
                 div {
@@ -30,7 +39,7 @@ pub fn HomePage() -> Element {
                         class: "lg:w-80 flex-shrink-0",
                         div {
                             class: "lg:sticky lg:top-24",
-                            Projects {}
+                            Projects { site: site.clone() }
                         }
                     }
                 }
@@ -43,12 +52,14 @@ pub fn HomePage() -> Element {
                     class: "max-w-7xl mx-auto px-4 flex flex-col md:flex-row justify-between items-center gap-4",
                     div {
                         class: "text-sm",
-                        "© 2026 Nzuzo Magagula. Built with Rust & Dioxus."
+                        "{copyright}"
                     }
                     div {
                         class: "flex gap-6 text-sm font-medium",
-                        a { href: "#", class: "hover:text-primary", "RSS" }
-                        a { href: "https://github.com/nzuzo-newsnet", class: "hover:text-primary", "Github" }
+                        a { href: "/feed.xml", class: "hover:text-primary", "RSS" }
+                        if let Some(github) = owner.as_ref().and_then(github_url) {
+                            a { href: "{github}", class: "hover:text-primary", "Github" }
+                        }
                         a { href: "#", class: "hover:text-primary", "Source" }
                     }
                 }
@@ -57,8 +68,24 @@ pub fn HomePage() -> Element {
     }
 }
 
+/// The owner's GitHub profile from their registry links
+fn github_url(author: &Author) -> Option<String> {
+    author
+        .links
+        .iter()
+        .find(|link| link.label.eq_ignore_ascii_case("github"))
+        .map(|link| link.url.clone())
+}
+
 #[component]
-fn Hero() -> Element {
+fn Hero(owner: Option<Author>) -> Element {
+    let owner_name = owner.as_ref().map(|a| a.name.clone()).unwrap_or_default();
+    let avatar = owner
+        .as_ref()
+        .and_then(|a| a.avatar.clone())
+        .unwrap_or_else(|| "/main_image.jpg".to_string());
+    let github = owner.as_ref().and_then(github_url);
+
     rsx! {
         div {
             class: "mb-12 py-8 md:py-12 border-b border-base-300 min-h-[60dvh] flex items-center",
@@ -83,13 +110,15 @@ fn Hero() -> Element {
                             class: "inline-flex items-center px-6 py-3 border border-transparent text-base font-bold rounded-xl shadow-lg text-primary-content bg-primary hover:bg-primary-focus transition-all hover:scale-105",
                             "View Articles"
                         }
-                        a {
-                            href: "https://github.com/nzuzo-newsnet",
-                            class: "inline-flex items-center px-6 py-3 border-2 border-base-300 text-base font-bold rounded-xl text-base-content hover:bg-base-200 transition-all hover:scale-105",
-                            svg { class: "mr-2 h-5 w-5", fill: "currentColor", view_box: "0 0 24 24", xmlns: "http://www.w3.org/2000/svg",
-                                path { d: "M12 .297c-6.63 0-12 5.373-12 12 0 5.303 3.438 9.8 8.205 11.385.6.113.82-.258.82-.577 0-.285-.01-1.04-.015-2.04-3.338.724-4.042-1.61-4.042-1.61C4.422 18.07 3.633 17.7 3.633 17.7c-1.087-.744.084-.729.084-.729 1.205.084 1.838 1.236 1.838 1.236 1.07 1.835 2.809 1.305 3.495.998.108-.776.417-1.305.76-1.605-2.665-.3-5.466-1.332-5.466-5.93 0-1.31.465-2.38 1.235-3.22-.135-.303-.54-1.523.105-3.176 0 0 1.005-.322 3.3 1.23.96-.267 1.98-.399 3-.405 1.02.006 2.04.138 3 .405 2.28-1.552 3.285-1.23 3.285-1.23.645 1.653.24 2.873.12 3.176.765.84 1.23 1.91 1.23 3.22 0 4.61-2.805 5.625-5.475 5.92.42.36.81 1.096.81 2.22 0 1.606-.015 2.896-.015 3.286 0 .315.21.69.825.57C20.565 22.092 24 17.592 24 12.297c0-6.627-5.373-12-12-12" }
+                        if let Some(github) = github {
+                            a {
+                                href: "{github}",
+                                class: "inline-flex items-center px-6 py-3 border-2 border-base-300 text-base font-bold rounded-xl text-base-content hover:bg-base-200 transition-all hover:scale-105",
+                                svg { class: "mr-2 h-5 w-5", fill: "currentColor", view_box: "0 0 24 24", xmlns: "http://www.w3.org/2000/svg",
+                                    path { d: "M12 .297c-6.63 0-12 5.373-12 12 0 5.303 3.438 9.8 8.205 11.385.6.113.82-.258.82-.577 0-.285-.01-1.04-.015-2.04-3.338.724-4.042-1.61-4.042-1.61C4.422 18.07 3.633 17.7 3.633 17.7c-1.087-.744.084-.729.084-.729 1.205.084 1.838 1.236 1.838 1.236 1.07 1.835 2.809 1.305 3.495.998.108-.776.417-1.305.76-1.605-2.665-.3-5.466-1.332-5.466-5.93 0-1.31.465-2.38 1.235-3.22-.135-.303-.54-1.523.105-3.176 0 0 1.005-.322 3.3 1.23.96-.267 1.98-.399 3-.405 1.02.006 2.04.138 3 .405 2.28-1.552 3.285-1.23 3.285-1.23.645 1.653.24 2.873.12 3.176.765.84 1.23 1.91 1.23 3.22 0 4.61-2.805 5.625-5.475 5.92.42.36.81 1.096.81 2.22 0 1.606-.015 2.896-.015 3.286 0 .315.21.69.825.57C20.565 22.092 24 17.592 24 12.297c0-6.627-5.373-12-12-12" }
+                                }
+                                "Github"
                             }
-                            "Github"
                         }
                     }
                 }
@@ -104,9 +133,9 @@ fn Hero() -> Element {
                     div {
                         class: "relative w-full h-full rounded-3xl overflow-hidden border-4 border-base-300 shadow-2xl transition-transform duration-500 group-hover:scale-105",
                         img {
-                            src: "/main_image.jpg",
+                            src: "{avatar}",
                             class: "w-full h-full object-cover transition-transform duration-700 group-hover:scale-110",
-                            alt: "Nzuzo Magagula"
+                            alt: "{owner_name}"
                         }
                     }
                 }
@@ -1,15 +1,20 @@
-use crate::markdown_management::github::{GitHubAccountType, GitHubRepo, fetch_github_repos};
+use crate::markdown_management::github::{GitHubRepo, fetch_github_repos};
+use crate::markdown_management::PublicSiteConfig;
 use dioxus::prelude::*;
 
 #[component]
-pub fn Projects() -> Element {
-    let repos = use_resource(|| async move {
-        fetch_github_repos(
-            GitHubAccountType::User,
-            "newsnet-africa".to_string(),
-        )
-        .await
-        .unwrap_or_default()
+pub fn Projects(site: PublicSiteConfig) -> Element {
+    let github = site.github.clone();
+    let repos = use_resource(move || {
+        let github = github.clone();
+        async move {
+            match github {
+                Some(github) => fetch_github_repos(github.account_type, github.account)
+                    .await
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            }
+        }
     });
 
     rsx! {
@@ -44,14 +49,16 @@ pub fn Projects() -> Element {
                 }
             }
 
-            div {
-                class: "mt-6 pt-4 border-t border-base-300",
-                a {
-                    href: "https://github.com/nzuzo-newsnet",
-                    class: "text-sm text-primary hover:text-primary-focus flex items-center gap-1 font-medium transition-colors group",
-                    "View all repositories"
-                    svg { class: "w-4 h-4 group-hover:translate-x-1 transition-transform", fill: "none", stroke: "currentColor", view_box: "0 0 24 24", xmlns: "http://www.w3.org/2000/svg",
-                        path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M9 5l7 7-7 7" }
+            if let Some(ref github) = site.github {
+                div {
+                    class: "mt-6 pt-4 border-t border-base-300",
+                    a {
+                        href: "{github.profile_url()}",
+                        class: "text-sm text-primary hover:text-primary-focus flex items-center gap-1 font-medium transition-colors group",
+                        "View all repositories"
+                        svg { class: "w-4 h-4 group-hover:translate-x-1 transition-transform", fill: "none", stroke: "currentColor", view_box: "0 0 24 24", xmlns: "http://www.w3.org/2000/svg",
+                            path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M9 5l7 7-7 7" }
+                        }
                     }
                 }
             }
@@ -63,15 +70,19 @@ pub fn Projects() -> Element {
                 p { class: "text-sm mb-4 text-base-content/70", "Interested in Rust or distributed systems? Let's chat." }
                 div {
                     class: "flex gap-2",
-                    a {
-                        href: "https://github.com/nzuzo-newsnet",
-                        class: "flex-1 py-2 text-center text-sm bg-primary text-primary-content rounded-md font-medium hover:bg-primary-focus transition-colors",
-                        "Github"
+                    for link in site.social.iter() {
+                        a {
+                            href: "{link.url}",
+                            class: "flex-1 py-2 text-center text-sm bg-primary text-primary-content rounded-md font-medium hover:bg-primary-focus transition-colors",
+                            "{link.label}"
+                        }
                     }
-                    a {
-                        href: "mailto:nzuzo@example.com",
-                        class: "flex-1 py-2 text-center text-sm rounded-md font-medium border border-base-300 hover:bg-base-200 transition-colors",
-                        "Email"
+                    if let Some(ref email) = site.email {
+                        a {
+                            href: "mailto:{email}",
+                            class: "flex-1 py-2 text-center text-sm rounded-md font-medium border border-base-300 hover:bg-base-200 transition-colors",
+                            "Email"
+                        }
                     }
                 }
             }
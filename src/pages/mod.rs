@@ -8,3 +8,8 @@ pub mod reading_page;
 pub mod series_page;
 pub mod series_detail_page;
 pub mod articles_page;
+pub mod author_page;
+pub mod search_page;
+pub mod saved_page;
+pub mod taxonomy_page;
+pub mod archive_page;
@@ -1,90 +1,41 @@
+use crate::markdown_management::{fetch_reading_list, Book, ReadingStatus};
+use crate::markdown_management::static_data::exported;
 use dioxus::prelude::*;
 
-#[derive(Clone, PartialEq)]
-struct ReadingItem {
-    title: String,
-    author: String,
-    description: String,
-    status: ReadingStatus,
-    link: Option<String>,
-}
-
-#[derive(Clone, PartialEq)]
-enum ReadingStatus {
-    CurrentlyReading,
-    Read,
-    WantToRead,
-}
-
-impl ReadingStatus {
-    fn as_str(&self) -> &str {
-        match self {
-            ReadingStatus::CurrentlyReading => "Currently Reading",
-            ReadingStatus::Read => "Read",
-            ReadingStatus::WantToRead => "Want to Read",
-        }
-    }
-
-    fn badge_class(&self) -> &str {
-        match self {
-            ReadingStatus::CurrentlyReading => "badge-primary",
-            ReadingStatus::Read => "badge-success",
-            ReadingStatus::WantToRead => "badge-ghost",
-        }
+fn badge_class(status: ReadingStatus) -> &'static str {
+    match status {
+        ReadingStatus::CurrentlyReading => "badge-primary",
+        ReadingStatus::Read => "badge-success",
+        ReadingStatus::WantToRead => "badge-ghost",
+        ReadingStatus::DidNotFinish => "badge-warning",
     }
 }
 
+/// The reading list from `reading.toml`, filtered by status in the browser
 #[component]
 pub fn ReadingPage() -> Element {
-    // Sample reading list - replace with your actual reading list
-    let reading_list = vec![
-        ReadingItem {
-            title: "The Rust Programming Language".to_string(),
-            author: "Steve Klabnik, Carol Nichols".to_string(),
-            description: "The official Rust book, covering everything from basics to advanced concepts.".to_string(),
-            status: ReadingStatus::Read,
-            link: Some("https://doc.rust-lang.org/book/".to_string()),
-        },
-        ReadingItem {
-            title: "Designing Data-Intensive Applications".to_string(),
-            author: "Martin Kleppmann".to_string(),
-            description: "Essential reading for understanding the architecture of modern data systems.".to_string(),
-            status: ReadingStatus::CurrentlyReading,
-            link: None,
-        },
-        ReadingItem {
-            title: "Clean Architecture".to_string(),
-            author: "Robert C. Martin".to_string(),
-            description: "A guide to software structure and design principles.".to_string(),
-            status: ReadingStatus::Read,
-            link: None,
-        },
-        ReadingItem {
-            title: "The Art of Doing Science and Engineering".to_string(),
-            author: "Richard Hamming".to_string(),
-            description: "Insights on learning and doing great work in technical fields.".to_string(),
-            status: ReadingStatus::WantToRead,
-            link: None,
-        },
-        ReadingItem {
-            title: "Release It!".to_string(),
-            author: "Michael T. Nygard".to_string(),
-            description: "Design and deploy production-ready software.".to_string(),
-            status: ReadingStatus::CurrentlyReading,
-            link: None,
-        },
-    ];
+    let reading_data = use_server_future(|| async move {
+        let status: Option<ReadingStatus> = None;
+        exported!(fetch_reading_list(status)).await.ok()
+    })?;
+    // An invalid `reading.toml` shows an error instead of an empty list
+    let failed = matches!(*reading_data.read(), Some(None));
+    let reading_list = reading_data.read().clone().flatten().unwrap_or_default();
 
     let mut selected_status = use_signal(|| None::<ReadingStatus>);
+    let selected = *selected_status.read();
 
-    let filtered_items: Vec<ReadingItem> = if let Some(ref status) = *selected_status.read() {
-        reading_list.iter()
-            .filter(|item| item.status == *status)
-            .cloned()
-            .collect()
-    } else {
-        reading_list.clone()
-    };
+    let filtered_items: Vec<Book> = reading_list
+        .iter()
+        .filter(|book| selected.is_none_or(|status| book.status == status))
+        .cloned()
+        .collect();
+
+    // Only offer statuses that some book has
+    let statuses: Vec<ReadingStatus> = ReadingStatus::ALL
+        .into_iter()
+        .filter(|status| reading_list.iter().any(|book| book.status == *status))
+        .collect();
 
     rsx! {
         main {
@@ -109,32 +60,35 @@ pub fn ReadingPage() -> Element {
             div {
                 class: "flex gap-3 mb-8 flex-wrap",
                 button {
-                    class: if selected_status.read().is_none() { "btn btn-primary" } else { "btn btn-ghost" },
+                    class: if selected.is_none() { "btn btn-primary" } else { "btn btn-ghost" },
                     onclick: move |_| selected_status.set(None),
                     "All"
                 }
-                button {
-                    class: if matches!(*selected_status.read(), Some(ReadingStatus::CurrentlyReading)) { "btn btn-primary" } else { "btn btn-ghost" },
-                    onclick: move |_| selected_status.set(Some(ReadingStatus::CurrentlyReading)),
-                    "Currently Reading"
-                }
-                button {
-                    class: if matches!(*selected_status.read(), Some(ReadingStatus::Read)) { "btn btn-primary" } else { "btn btn-ghost" },
-                    onclick: move |_| selected_status.set(Some(ReadingStatus::Read)),
-                    "Read"
-                }
-                button {
-                    class: if matches!(*selected_status.read(), Some(ReadingStatus::WantToRead)) { "btn btn-primary" } else { "btn btn-ghost" },
-                    onclick: move |_| selected_status.set(Some(ReadingStatus::WantToRead)),
-                    "Want to Read"
+                for status in statuses {
+                    button {
+                        class: if selected == Some(status) { "btn btn-primary" } else { "btn btn-ghost" },
+                        onclick: move |_| selected_status.set(Some(status)),
+                        "{status.label()}"
+                    }
                 }
             }
 
             // Reading list
             div {
                 class: "space-y-4",
-                for item in filtered_items {
-                    ReadingCard { item: item.clone() }
+                if failed {
+                    div {
+                        class: "text-center py-12",
+                        p {
+                            class: "text-lg text-error",
+                            "Failed to load the reading list"
+                        }
+                    }
+                } else if filtered_items.is_empty() {
+                    p { class: "text-center py-8 text-base-content/50", "Nothing on the list yet." }
+                }
+                for book in filtered_items {
+                    ReadingCard { book }
                 }
             }
             }
@@ -143,10 +97,31 @@ pub fn ReadingPage() -> Element {
 }
 
 #[component]
-fn ReadingCard(item: ReadingItem) -> Element {
+fn ReadingCard(book: Book) -> Element {
+    let cover = book.cover_url();
+    let dates = match (book.started, book.finished) {
+        (Some(started), Some(finished)) => {
+            Some(format!("{} – {}", started.to_long_string(), finished.to_long_string()))
+        }
+        (Some(started), None) => Some(format!("Started {}", started.to_long_string())),
+        (None, Some(finished)) => Some(format!("Finished {}", finished.to_long_string())),
+        (None, None) => None,
+    };
+
     rsx! {
         article {
-            class: "card card-lg bg-base-200 hover:shadow-lg transition-shadow",
+            class: "card card-lg card-side bg-base-200 hover:shadow-lg transition-shadow",
+            if let Some(cover) = cover {
+                figure {
+                    class: "w-24 flex-shrink-0 pl-6 py-6",
+                    img {
+                        src: "{cover}",
+                        class: "w-full rounded shadow",
+                        alt: "Cover of {book.title}",
+                        loading: "lazy",
+                    }
+                }
+            }
             div {
                 class: "card-body",
                 div {
@@ -154,30 +129,57 @@ fn ReadingCard(item: ReadingItem) -> Element {
                     div {
                         h3 {
                             class: "card-title text-xl mb-1",
-                            if let Some(link) = &item.link {
+                            if let Some(link) = &book.link {
                                 a {
                                     href: "{link}",
                                     target: "_blank",
                                     class: "link link-primary",
-                                    "{item.title}"
+                                    "{book.title}"
                                 }
                             } else {
-                                "{item.title}"
+                                "{book.title}"
                             }
                         }
                         p {
                             class: "text-sm text-base-content opacity-60",
-                            "by {item.author}"
+                            "by {book.author}"
                         }
                     }
                     span {
-                        class: "badge {item.status.badge_class()}",
-                        "{item.status.as_str()}"
+                        class: "badge {badge_class(book.status)}",
+                        "{book.status.label()}"
                     }
                 }
-                p {
-                    class: "text-base-content opacity-70",
-                    "{item.description}"
+                if let Some(description) = &book.description {
+                    p {
+                        class: "text-base-content opacity-70",
+                        "{description}"
+                    }
+                }
+                if let Some(notes) = &book.notes {
+                    blockquote {
+                        class: "border-l-4 border-primary/40 pl-4 italic text-base-content/70 whitespace-pre-line",
+                        "{notes}"
+                    }
+                }
+                div {
+                    class: "flex flex-wrap items-center gap-4 mt-2 text-sm text-base-content/60",
+                    if let Some(rating) = book.rating {
+                        span { class: "text-warning", title: "{rating} out of 5", "★ {rating}/5" }
+                    }
+                    if let Some(dates) = dates {
+                        span { "{dates}" }
+                    }
+                    if let Some(isbn) = &book.isbn {
+                        span { "ISBN {isbn}" }
+                    }
+                    if let Some(review) = &book.review {
+                        Link {
+                            to: format!("/article/{}", review),
+                            class: "link link-primary font-medium",
+                            "Read my review"
+                        }
+                    }
                 }
             }
         }
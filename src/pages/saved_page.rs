@@ -0,0 +1,108 @@
+use dioxus::prelude::*;
+use crate::markdown_management::{clear_saved_content, list_saved_articles, remove_saved_article};
+
+#[component]
+pub fn SavedPage() -> Element {
+    let mut saved = use_resource(|| async move { list_saved_articles().await });
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-4xl",
+
+                // Header
+                div {
+                    class: "mb-8 flex flex-wrap gap-4 items-end justify-between",
+                    div {
+                        h1 {
+                            class: "text-3xl font-bold mb-4",
+                            "Saved for Offline"
+                        }
+                        p {
+                            class: "text-lg text-base-content opacity-70",
+                            "Articles stored in this browser, readable without a connection"
+                        }
+                    }
+                    button {
+                        class: "btn btn-sm btn-error btn-outline",
+                        onclick: move |_| async move {
+                            if let Err(e) = clear_saved_content().await {
+                                dioxus::logger::tracing::error!("Failed to clear saved content: {}", e);
+                            }
+                            saved.restart();
+                        },
+                        "Clear all"
+                    }
+                }
+
+                match saved.read().as_ref() {
+                    Some(Ok(articles)) if articles.is_empty() => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-base-content opacity-70",
+                                "Nothing saved yet. Use \"Save series for offline\" on a series page."
+                            }
+                        }
+                    },
+                    Some(Ok(articles)) => rsx! {
+                        ul {
+                            class: "space-y-2",
+                            for article in articles.iter().cloned() {
+                                li {
+                                    key: "{article.path}",
+                                    class: "flex items-center gap-4 p-4 rounded-box bg-base-200",
+                                    div {
+                                        class: "flex-1 min-w-0",
+                                        Link {
+                                            to: format!("/article/{}", article.path.trim_end_matches(".md")),
+                                            class: "font-semibold hover:text-primary",
+                                            "{article.title}"
+                                        }
+                                        if let Some(ref series) = article.series {
+                                            p {
+                                                class: "text-sm text-base-content opacity-60",
+                                                "{series}"
+                                            }
+                                        }
+                                    }
+                                    button {
+                                        class: "btn btn-xs btn-ghost",
+                                        onclick: {
+                                            let path = article.path.clone();
+                                            move |_| {
+                                                let path = path.clone();
+                                                async move {
+                                                    if let Err(e) = remove_saved_article(path).await {
+                                                        dioxus::logger::tracing::error!("Failed to remove saved article: {}", e);
+                                                    }
+                                                    saved.restart();
+                                                }
+                                            }
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        div {
+                            class: "alert alert-error",
+                            "Failed to load saved content: {e}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use dioxus::prelude::*;
+use crate::markdown_management::{fetch_tags, search_articles, SearchFilters, SearchResult};
+use crate::markdown_management::static_data::exported;
+
+#[component]
+pub fn SearchPage(query: String) -> Element {
+    let mut input = use_signal(|| query.clone());
+    let mut tag = use_signal(|| None::<String>);
+    let navigator = use_navigator();
+
+    // Results follow the input as the reader types, searched in the browser when possible
+    let results = use_resource(move || async move {
+        let query = input.read().trim().to_string();
+        if query.is_empty() {
+            return Some(Vec::new());
+        }
+        let filters = SearchFilters {
+            tags: tag.read().iter().cloned().collect(),
+            ..Default::default()
+        };
+        search_articles(query, filters).await.ok()
+    });
+
+    let tags = use_resource(|| async move { exported!(fetch_tags()).await.ok() });
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-4xl",
+
+                // Header
+                div {
+                    class: "mb-8",
+                    h1 {
+                        class: "text-3xl font-bold mb-4",
+                        "Search"
+                    }
+                    form {
+                        class: "flex flex-col sm:flex-row gap-3",
+                        role: "search",
+                        // Keep the URL shareable once the reader settles on a query
+                        onsubmit: move |event| {
+                            event.prevent_default();
+                            navigator.replace(crate::Route::Search { query: input.read().trim().to_string() });
+                        },
+                        input {
+                            r#type: "search",
+                            class: "input input-bordered flex-1",
+                            placeholder: "Search titles, headings, tags and text",
+                            aria_label: "Search articles",
+                            autofocus: true,
+                            value: "{input}",
+                            oninput: move |event| input.set(event.value()),
+                        }
+                        select {
+                            class: "select select-bordered",
+                            aria_label: "Filter by tag",
+                            onchange: move |event| {
+                                let value = event.value();
+                                tag.set(if value.is_empty() { None } else { Some(value) });
+                            },
+                            option { value: "", selected: tag.read().is_none(), "All tags" }
+                            if let Some(Some(tags)) = tags.read().as_ref() {
+                                for (name, count) in tags.iter() {
+                                    option {
+                                        key: "{name}",
+                                        value: "{name}",
+                                        selected: tag.read().as_deref() == Some(name.as_str()),
+                                        "{name} ({count})"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Results
+                match results.read().as_ref() {
+                    Some(Some(_)) if input.read().trim().is_empty() => rsx! {
+                        p {
+                            class: "text-center py-12 text-base-content opacity-70",
+                            "Type to search every article"
+                        }
+                    },
+                    Some(Some(results)) if results.is_empty() => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-base-content opacity-70",
+                                "No articles match \"{input}\""
+                            }
+                        }
+                    },
+                    Some(Some(results)) => rsx! {
+                        p {
+                            class: "text-sm text-base-content opacity-60 mb-4",
+                            "{results.len()} results"
+                        }
+                        div {
+                            class: "space-y-4",
+                            for result in results.iter() {
+                                SearchResultCard {
+                                    key: "{result.id}",
+                                    result: result.clone(),
+                                    on_tag: move |name| tag.set(Some(name)),
+                                }
+                            }
+                        }
+                    },
+                    Some(None) => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-error",
+                                "Search failed"
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SearchResultCard(result: SearchResult, on_tag: EventHandler<String>) -> Element {
+    rsx! {
+        div {
+            class: "card bg-base-100 shadow-md border-2 border-transparent hover:border-primary transition-all duration-300",
+            div {
+                class: "card-body",
+                Link {
+                    to: format!("/article/{}", result.id.trim_end_matches(".md")),
+                    class: "card-title text-lg hover:text-primary",
+                    "{result.title}"
+                }
+
+                div {
+                    class: "flex flex-wrap gap-2 text-xs",
+                    if let Some(ref date) = result.date {
+                        span {
+                            class: "badge badge-sm badge-outline",
+                            "📅 {date}"
+                        }
+                    }
+                    for series in result.series.iter() {
+                        span {
+                            key: "{series}",
+                            class: "badge badge-sm badge-secondary",
+                            "{series}"
+                        }
+                    }
+                }
+
+                if !result.snippet.is_empty() {
+                    p {
+                        class: "text-sm text-base-content opacity-80",
+                        for part in result.snippet.iter() {
+                            if part.highlight {
+                                mark {
+                                    class: "bg-warning/40 text-base-content rounded px-0.5",
+                                    "{part.text}"
+                                }
+                            } else {
+                                span { "{part.text}" }
+                            }
+                        }
+                    }
+                }
+
+                if !result.tags.is_empty() {
+                    div {
+                        class: "flex flex-wrap gap-1 mt-2",
+                        for name in result.tags.iter().cloned() {
+                            button {
+                                key: "{name}",
+                                class: "badge badge-xs badge-ghost cursor-pointer hover:badge-primary",
+                                title: "Only show articles tagged {name}",
+                                onclick: {
+                                    let name = name.clone();
+                                    move |_| on_tag.call(name.clone())
+                                },
+                                "#{name}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
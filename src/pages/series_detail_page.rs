@@ -1,13 +1,27 @@
 use dioxus::prelude::*;
-use crate::markdown_management::fetch_series_by_name;
+use crate::markdown_management::{
+    fetch_series_by_name, fetch_series_progress, progress_key, save_series_offline, ReadingProgress,
+};
+use crate::markdown_management::static_data::exported;
+use crate::shared::locale::use_locale;
 use dioxus_markdown::Markdown;
 
 #[component]
 pub fn SeriesDetailPage(series_name: String) -> Element {
-    // Fetch series data from server
-    let series_data = use_resource(move || {
+    let locale = use_locale();
+
+    // Fetch series data, prefetched during server rendering
+    let name = series_name.clone();
+    let series_data = use_server_future(move || {
+        let name = name.clone();
+        let locale = locale();
+        async move { exported!(fetch_series_by_name(name, locale)).await.ok() }
+    })?;
+
+    // Reading progress stored in the browser, keyed by article path
+    let progress = use_resource(move || {
         let name = series_name.clone();
-        async move { fetch_series_by_name(name).await.ok() }
+        async move { fetch_series_progress(name).await.unwrap_or_default() }
     });
 
     rsx! {
@@ -63,6 +77,7 @@ pub fn SeriesDetailPage(series_name: String) -> Element {
                                             }
                                         }
                                     }
+                                    SaveSeriesButton { series_name: series.name.clone() }
                                 }
                             }
 
@@ -101,7 +116,11 @@ pub fn SeriesDetailPage(series_name: String) -> Element {
                                         for (idx, article) in series.articles.iter().enumerate() {
                                             ArticleCard {
                                                 article: article.clone(),
-                                                index: idx + 1
+                                                index: idx + 1,
+                                                progress: progress
+                                                    .read()
+                                                    .as_ref()
+                                                    .and_then(|progress| progress.get(&progress_key(&article.metadata)).cloned()),
                                             }
                                         }
                                     }
@@ -154,7 +173,11 @@ pub fn SeriesDetailPage(series_name: String) -> Element {
 }
 
 #[component]
-fn ArticleCard(article: crate::markdown_management::ArticleWithMetadata, index: usize) -> Element {
+fn ArticleCard(
+    article: crate::markdown_management::ArticleWithMetadata,
+    index: usize,
+    progress: Option<ReadingProgress>,
+) -> Element {
     rsx! {
         Link {
             to: format!("/article/{}", article.metadata.path.trim_end_matches(".md")),
@@ -209,6 +232,22 @@ fn ArticleCard(article: crate::markdown_management::ArticleWithMetadata, index:
                                 }
                             }
                         }
+
+                        // Reading progress
+                        if let Some(ref reading) = progress {
+                            if reading.completed {
+                                span {
+                                    class: "badge badge-success badge-sm mt-2",
+                                    "✓ Read"
+                                }
+                            } else {
+                                progress {
+                                    class: "progress progress-primary w-full mt-2",
+                                    value: "{(reading.progress * 100.0).round()}",
+                                    max: "100"
+                                }
+                            }
+                        }
                     }
 
                     // Arrow icon
@@ -233,3 +272,65 @@ fn ArticleCard(article: crate::markdown_management::ArticleWithMetadata, index:
         }
     }
 }
+
+/// Progress of saving a series for offline reading
+#[derive(Clone, PartialEq)]
+enum SaveState {
+    Idle,
+    Saving,
+    Saved(usize),
+    Failed(String),
+}
+
+/// Stores every part of the series in the browser for offline reading
+#[component]
+fn SaveSeriesButton(series_name: String) -> Element {
+    let mut state = use_signal(|| SaveState::Idle);
+    let locale = use_locale();
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-2 items-center ml-auto",
+            button {
+                class: "btn btn-sm btn-outline gap-2",
+                disabled: state() == SaveState::Saving,
+                onclick: move |_| {
+                    let name = series_name.clone();
+                    spawn(async move {
+                        state.set(SaveState::Saving);
+                        match save_series_offline(name, locale()).await {
+                            Ok(count) => state.set(SaveState::Saved(count)),
+                            Err(e) => state.set(SaveState::Failed(e.to_string())),
+                        }
+                    });
+                },
+                if state() == SaveState::Saving {
+                    span { class: "loading loading-spinner loading-xs" }
+                    "Saving..."
+                } else {
+                    "Save series for offline"
+                }
+            }
+            match state() {
+                SaveState::Saved(count) => rsx! {
+                    span {
+                        class: "text-sm text-success",
+                        "Saved {count} articles"
+                    }
+                },
+                SaveState::Failed(error) => rsx! {
+                    span {
+                        class: "text-sm text-error",
+                        "{error}"
+                    }
+                },
+                _ => rsx! {},
+            }
+            Link {
+                to: crate::Route::Saved {},
+                class: "btn btn-sm btn-ghost",
+                "Saved content"
+            }
+        }
+    }
+}
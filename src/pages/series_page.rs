@@ -1,12 +1,17 @@
 use dioxus::prelude::*;
 use crate::markdown_management::{fetch_all_series, SeriesData};
+use crate::markdown_management::static_data::exported;
+use crate::shared::locale::use_locale;
 
 #[component]
 pub fn SeriesPage() -> Element {
+    let locale = use_locale();
+
     // Fetch series data from server
-    let series_data = use_resource(|| async move {
-        fetch_all_series().await.ok()
-    });
+    let series_data = use_server_future(move || async move {
+        let locale = locale();
+        exported!(fetch_all_series(locale)).await.ok()
+    })?;
 
     rsx! {
         main {
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+use crate::markdown_management::{fetch_articles_by_term, fetch_taxonomy_index, PaginatedArticles, TaxonomyKind};
+use crate::markdown_management::static_data::exported;
+use crate::pages::articles_page::{ArticleCard, Pagination};
+use crate::shared::locale::use_locale;
+
+/// The page listing articles under `term`
+pub fn term_route(kind: TaxonomyKind, term: &str) -> crate::Route {
+    match kind {
+        TaxonomyKind::Tag => crate::Route::Tag { tag: term.to_string() },
+        TaxonomyKind::Topic => crate::Route::Topic { topic: term.to_string() },
+        TaxonomyKind::Category => crate::Route::Category { category: term.to_string() },
+    }
+}
+
+/// A badge linking to the page for a tag, topic or category
+#[component]
+pub fn TermBadge(kind: TaxonomyKind, term: String, class: String) -> Element {
+    let text = match kind {
+        TaxonomyKind::Tag => format!("#{}", term),
+        _ => term.clone(),
+    };
+
+    rsx! {
+        Link {
+            to: term_route(kind, &term),
+            class: "{class} hover:badge-accent transition-colors",
+            "{text}"
+        }
+    }
+}
+
+#[component]
+pub fn TaxonomyIndexPage() -> Element {
+    let index = use_server_future(|| async move { exported!(fetch_taxonomy_index()).await.ok() })?;
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-5xl",
+
+                // Header
+                div {
+                    class: "mb-8",
+                    h1 {
+                        class: "text-3xl font-bold mb-4",
+                        "Browse by Subject"
+                    }
+                    p {
+                        class: "text-lg text-base-content opacity-70",
+                        "Every category, topic and tag, with the number of articles under each"
+                    }
+                }
+
+                match index.read().as_ref() {
+                    Some(Some(index)) => rsx! {
+                        div {
+                            class: "space-y-10",
+                            TermSection { kind: TaxonomyKind::Category, counts: index.categories.clone(), descriptions: index.descriptions.clone() }
+                            TermSection { kind: TaxonomyKind::Topic, counts: index.topics.clone(), descriptions: index.descriptions.clone() }
+                            TermSection { kind: TaxonomyKind::Tag, counts: index.tags.clone(), descriptions: index.descriptions.clone() }
+                        }
+                    },
+                    Some(None) => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-error",
+                                "Failed to load tags"
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TermSection(
+    kind: TaxonomyKind,
+    counts: BTreeMap<String, usize>,
+    descriptions: BTreeMap<String, String>,
+) -> Element {
+    if counts.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        section {
+            h2 {
+                class: "text-xl font-bold mb-4",
+                "{kind.plural()}"
+            }
+            div {
+                class: "flex flex-wrap gap-2",
+                for (term, count) in counts.iter() {
+                    Link {
+                        key: "{term}",
+                        to: term_route(kind, term),
+                        title: descriptions.get(term).cloned().unwrap_or_default(),
+                        class: "badge badge-lg badge-outline gap-2 hover:badge-primary transition-colors",
+                        "{term}"
+                        span { class: "opacity-60", "{count}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn TaxonomyPage(kind: TaxonomyKind, term: String) -> Element {
+    let mut current_page = use_signal(|| 1usize);
+    let locale = use_locale();
+
+    let title = term.clone();
+    let description = use_server_future({
+        let term = term.clone();
+        move || {
+            let term = term.clone();
+            async move {
+                let index = exported!(fetch_taxonomy_index()).await.ok()?;
+                index.descriptions.get(&term).cloned()
+            }
+        }
+    })?;
+    let articles_data = use_server_future(move || {
+        let term = term.clone();
+        let page = current_page();
+        let per_page = PaginatedArticles::PER_PAGE;
+        let locale = locale();
+        async move { exported!(fetch_articles_by_term(kind, term, page, per_page, locale)).await.ok() }
+    })?;
+
+    rsx! {
+        main {
+            class: "flex-1 overflow-y-auto p-8",
+            div {
+                class: "container mx-auto max-w-7xl",
+
+                // Header
+                div {
+                    class: "mb-8",
+                    Link {
+                        to: crate::Route::Tags {},
+                        class: "btn btn-ghost btn-sm mb-4",
+                        "← All subjects"
+                    }
+                    p {
+                        class: "text-sm font-semibold uppercase tracking-wider text-primary mb-2",
+                        "{kind.label()}"
+                    }
+                    h1 {
+                        class: "text-3xl font-bold mb-4",
+                        "{title}"
+                    }
+                    if let Some(Some(description)) = description.read().as_ref() {
+                        p {
+                            class: "text-lg text-base-content opacity-70",
+                            "{description}"
+                        }
+                    }
+                }
+
+                match articles_data.read().as_ref() {
+                    Some(Some(paginated_data)) => rsx! {
+                        div {
+                            class: "mb-6",
+                            p {
+                                class: "text-sm text-base-content opacity-60",
+                                "{paginated_data.total_count} articles"
+                            }
+                        }
+
+                        div {
+                            class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6 mb-8",
+                            for article in &paginated_data.articles {
+                                ArticleCard { article: article.clone() }
+                            }
+                        }
+
+                        if paginated_data.total_pages > 1 {
+                            Pagination {
+                                current_page: paginated_data.page,
+                                total_pages: paginated_data.total_pages,
+                                on_page_change: move |page| {
+                                    current_page.set(page);
+                                }
+                            }
+                        }
+                    },
+                    Some(None) => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            p {
+                                class: "text-lg text-base-content opacity-70",
+                                "No articles found"
+                            }
+                            Link {
+                                to: crate::Route::Tags {},
+                                class: "btn btn-primary mt-4",
+                                "Browse all subjects"
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            class: "text-center py-12",
+                            span {
+                                class: "loading loading-spinner loading-lg"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
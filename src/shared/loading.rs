@@ -0,0 +1,21 @@
+use dioxus::prelude::*;
+
+/// Shows a spinner in place of a page while its server data loads
+///
+/// Pages fetch with `use_server_future`, so the server waits for the data
+/// and the first response is complete. On client-side navigation the
+/// spinner fills the gap instead of the whole app suspending.
+#[component]
+pub fn PageSuspense(children: Element) -> Element {
+    rsx! {
+        SuspenseBoundary {
+            fallback: |_| rsx! {
+                main {
+                    class: "flex-1 flex items-center justify-center p-8",
+                    span { class: "loading loading-spinner loading-lg" }
+                }
+            },
+            {children}
+        }
+    }
+}
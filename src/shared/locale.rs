@@ -0,0 +1,69 @@
+use dioxus::document::eval;
+use dioxus::prelude::*;
+
+use advanced_markdown_parser::negotiate_language;
+use crate::markdown_management::DEFAULT_LANGUAGE;
+use crate::markdown_management::static_data::exported_languages;
+
+/// The reader's preferred language, shared through context
+/// `None` until the saved preference (or the browser language) has loaded
+#[derive(Clone, Copy, PartialEq)]
+pub struct Locale(pub Signal<Option<String>>);
+
+/// Provide the locale context and load the saved preference (call once in `App`)
+pub fn use_locale_provider() {
+    let mut locale = use_signal(|| None::<String>);
+    use_context_provider(|| Locale(locale));
+
+    // Load locale from localStorage, falling back to the browser language
+    use_effect(move || {
+        spawn(async move {
+            let load_script = r#"
+                try {
+                    dioxus.send(localStorage.getItem('locale') || navigator.language || '');
+                } catch (e) {
+                    dioxus.send('');
+                }
+            "#;
+
+            // Server rendering assumed the default language; setting an
+            // equivalent locale would refetch every page's data after hydration
+            if let Ok(saved_locale) = eval(load_script).recv::<String>().await {
+                // A static copy only holds data for the languages it was exported in
+                let saved_locale = match exported_languages() {
+                    Some(languages) => {
+                        let languages: Vec<&str> = languages.iter().map(String::as_str).collect();
+                        negotiate_language(Some(&saved_locale), &languages).unwrap_or_default().to_string()
+                    }
+                    None => saved_locale,
+                };
+                let primary = saved_locale.split('-').next().unwrap_or_default();
+                if !primary.is_empty() && !primary.eq_ignore_ascii_case(DEFAULT_LANGUAGE) {
+                    locale.set(Some(saved_locale));
+                }
+            }
+        });
+    });
+}
+
+/// The reader's preferred language
+pub fn use_locale() -> Signal<Option<String>> {
+    use_context::<Locale>().0
+}
+
+/// Switch to `lang` and remember the choice for future visits
+pub fn set_locale(mut locale: Signal<Option<String>>, lang: String) {
+    // Quoted as a JSON string so the value can't break out of the literal
+    let quoted = serde_json::Value::String(lang.clone()).to_string();
+    let _ = eval(&format!(
+        r#"
+        try {{
+            localStorage.setItem('locale', {});
+        }} catch (e) {{
+            console.error('Failed to save locale:', e);
+        }}
+        "#,
+        quoted
+    ));
+    locale.set(Some(lang));
+}
@@ -1 +1,4 @@
 pub mod nav_bar;
+pub mod loading;
+pub mod locale;
+pub mod seo;
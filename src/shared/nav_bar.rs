@@ -1,3 +1,5 @@
+use crate::markdown_management::fetch_site_owner;
+use crate::markdown_management::static_data::exported;
 use dioxus::document::eval;
 use dioxus::prelude::*;
 
@@ -114,6 +116,7 @@ pub fn NavBar() -> Element {
             (crate::Route::Series {}, crate::Route::Series {}) => true,
             (crate::Route::SeriesDetail { .. }, crate::Route::Series {}) => true,
             (crate::Route::Articles {}, crate::Route::Articles {}) => true,
+            (crate::Route::Search { .. }, crate::Route::Search { .. }) => true,
             _ => false,
         }
     };
@@ -142,13 +145,10 @@ pub fn NavBar() -> Element {
                     Link {
                         to: "/",
                         class: "flex-shrink-0 flex items-center gap-3 group",
-                        div {
-                            class: "w-8 h-8 rounded-lg flex items-center justify-center font-bold text-xl bg-primary text-primary-content transition-transform group-hover:scale-110",
-                            "N"
-                        }
-                        span {
-                            class: "font-bold text-xl tracking-tight hidden sm:inline",
-                            "Nzuzo Magagula"
+                        // The bar renders outside page suspense, so the owner loads on its own
+                        SuspenseBoundary {
+                            fallback: |_| rsx! { OwnerBrand { name: String::new() } },
+                            SiteOwnerBrand {}
                         }
                     }
 
@@ -168,7 +168,9 @@ pub fn NavBar() -> Element {
                         }
                         
                         div { class: "h-6 w-px mx-2 bg-base-300" }
-                        
+
+                        SearchBox { class: "w-40 lg:w-56" }
+
                         // Theme Toggle (Quick switch between light/dark)
                         button {
                             class: "p-2 rounded-full hover:bg-base-200 transition-colors text-warning",
@@ -260,6 +262,13 @@ pub fn NavBar() -> Element {
                     class: "md:hidden border-t border-base-300 bg-base-100",
                     div {
                         class: "px-2 pt-2 pb-3 space-y-1",
+                        div {
+                            class: "px-3 pb-2",
+                            SearchBox {
+                                class: "w-full",
+                                on_search: move |_| is_mobile_menu_open.set(false),
+                            }
+                        }
                         for (path, label, _) in nav_links.iter() {
                             Link {
                                 to: path.to_string(),
@@ -301,3 +310,68 @@ pub fn NavBar() -> Element {
     }
 }
 
+/// The site owner's name from `authors.toml`
+#[component]
+fn SiteOwnerBrand() -> Element {
+    let owner_data = use_server_future(|| async move { exported!(fetch_site_owner()).await.ok() })?;
+    let name = owner_data.read().as_ref().cloned().flatten().map(|a| a.name).unwrap_or_default();
+
+    rsx! { OwnerBrand { name } }
+}
+
+/// Logo tile with the name's initial, followed by the name
+#[component]
+fn OwnerBrand(name: String) -> Element {
+    let initial = name.chars().next().map(String::from).unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "w-8 h-8 rounded-lg flex items-center justify-center font-bold text-xl bg-primary text-primary-content transition-transform group-hover:scale-110",
+            "{initial}"
+        }
+        span {
+            class: "font-bold text-xl tracking-tight hidden sm:inline",
+            "{name}"
+        }
+    }
+}
+
+/// Search input that opens the search page for the entered query
+#[component]
+fn SearchBox(class: String, on_search: Option<EventHandler<()>>) -> Element {
+    let mut query = use_signal(String::new);
+    let navigator = use_navigator();
+
+    rsx! {
+        form {
+            class: "{class}",
+            role: "search",
+            onsubmit: move |event| {
+                event.prevent_default();
+                let text = query.read().trim().to_string();
+                if text.is_empty() {
+                    return;
+                }
+                navigator.push(crate::Route::Search { query: text });
+                query.set(String::new());
+                if let Some(handler) = on_search {
+                    handler.call(());
+                }
+            },
+            label {
+                class: "input input-sm input-bordered flex items-center gap-2 w-full",
+                svg { class: "w-4 h-4 opacity-60", fill: "none", stroke: "currentColor", view_box: "0 0 24 24", xmlns: "http://www.w3.org/2000/svg",
+                    path { stroke_linecap: "round", stroke_linejoin: "round", stroke_width: "2", d: "M21 21l-4.35-4.35M17 11a6 6 0 11-12 0 6 6 0 0112 0z" }
+                }
+                input {
+                    r#type: "search",
+                    class: "grow min-w-0",
+                    placeholder: "Search articles",
+                    aria_label: "Search articles",
+                    value: "{query}",
+                    oninput: move |event| query.set(event.value()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use dioxus::prelude::*;
+
+use crate::markdown_management::{fetch_article_meta, fetch_page_meta, PageMeta};
+use crate::markdown_management::static_data::exported;
+
+/// Title, description, canonical URL, OpenGraph/Twitter cards and JSON-LD
+///
+/// Fetched with `use_server_future` so the tags are part of the server-rendered
+/// HTML that crawlers and link previews see. Each wrapper has its own suspense
+/// boundary so client-side navigation never waits on it. `noindex` keeps
+/// pages like search results out of search engines.
+#[component]
+pub fn PageSeo(
+    path: String,
+    #[props(!optional)] title: Option<String>,
+    #[props(default)] description: Option<String>,
+    #[props(default)] noindex: bool,
+) -> Element {
+    rsx! {
+        SuspenseBoundary {
+            fallback: |_| rsx! {},
+            // Keyed so the tags are refetched when a route's parameters change
+            PageSeoTags { key: "{path}", path, title, description, noindex }
+        }
+    }
+}
+
+#[component]
+fn PageSeoTags(path: String, title: Option<String>, description: Option<String>, noindex: bool) -> Element {
+    let meta = use_server_future(move || {
+        let (path, title, description) = (path.clone(), title.clone(), description.clone());
+        exported!(fetch_page_meta(path, title, description))
+    })?;
+
+    match meta() {
+        Some(Ok(meta)) => rsx! { HeadTags { meta, noindex } },
+        _ => rsx! {},
+    }
+}
+
+/// Head tags for an article, including its `BlogPosting` JSON-LD
+#[component]
+pub fn ArticleSeo(path: String) -> Element {
+    rsx! {
+        SuspenseBoundary {
+            fallback: |_| rsx! {},
+            ArticleSeoTags { key: "{path}", path }
+        }
+    }
+}
+
+#[component]
+fn ArticleSeoTags(path: String) -> Element {
+    let meta = use_server_future(move || {
+        let path = path.clone();
+        exported!(fetch_article_meta(path))
+    })?;
+
+    match meta() {
+        // Drafts and unlisted articles can be opened by URL but stay out of search engines
+        Some(Ok(meta)) => {
+            let noindex = meta.noindex;
+            rsx! { HeadTags { meta, noindex } }
+        }
+        _ => rsx! {},
+    }
+}
+
+#[component]
+fn HeadTags(meta: PageMeta, noindex: bool) -> Element {
+    let card = if meta.image.is_some() { "summary_large_image" } else { "summary" };
+
+    rsx! {
+        document::Title { "{meta.title}" }
+        document::Meta { name: "description", content: "{meta.description}" }
+        document::Link { rel: "canonical", href: "{meta.url}" }
+        if noindex {
+            document::Meta { name: "robots", content: "noindex" }
+        }
+
+        document::Meta { property: "og:type", content: "{meta.kind}" }
+        document::Meta { property: "og:title", content: "{meta.title}" }
+        document::Meta { property: "og:description", content: "{meta.description}" }
+        document::Meta { property: "og:url", content: "{meta.url}" }
+        document::Meta { property: "og:site_name", content: "{meta.site_name}" }
+        if let Some(ref lang) = meta.lang {
+            document::Meta { property: "og:locale", content: "{lang}" }
+        }
+        if let Some(ref image) = meta.image {
+            document::Meta { property: "og:image", content: "{image}" }
+            document::Meta { name: "twitter:image", content: "{image}" }
+        }
+        if let Some(ref published) = meta.published {
+            document::Meta { property: "article:published_time", content: "{published}" }
+        }
+        if let Some(ref modified) = meta.modified {
+            document::Meta { property: "article:modified_time", content: "{modified}" }
+        }
+        for author in meta.authors.iter() {
+            document::Meta { property: "article:author", content: "{author}" }
+        }
+        for tag in meta.tags.iter() {
+            document::Meta { property: "article:tag", content: "{tag}" }
+        }
+
+        document::Meta { name: "twitter:card", content: "{card}" }
+        document::Meta { name: "twitter:title", content: "{meta.title}" }
+        document::Meta { name: "twitter:description", content: "{meta.description}" }
+
+        // Inline so the JSON isn't HTML-escaped; crawlers read JSON-LD anywhere in the page
+        if let Some(ref json_ld) = meta.json_ld {
+            script { r#type: "application/ld+json", dangerous_inner_html: "{json_ld}" }
+        }
+    }
+}